@@ -0,0 +1,114 @@
+//! Generates the module declarations for every solver file, so adding a new day or year
+//! does not require any manual module declarations.
+//!
+//! Every file src/y<year>/d<day>.rs becomes the module y<year>::d<day>.
+//!
+//! Also generates a test for every example and for both parts of every puzzle input of a day with a
+//! solver file, like puzzle_tests::y2024::d20::example_a_ea2 and puzzle_tests::y2024::d20::puzzle_a.
+
+#[path = "src/input_files.rs"]
+mod input_files;
+
+use input_files::{example_files, read_input_file};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let src = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut modules = String::new();
+
+    for (year, year_dir) in numbered_entries(&src, "y", "") {
+        writeln!(modules, "mod y{year} {{").unwrap();
+
+        for (day, day_file) in numbered_entries(&year_dir, "d", ".rs") {
+            writeln!(modules, "    #[path = {:?}]", day_file.display().to_string()).unwrap();
+            writeln!(modules, "    pub mod d{day};").unwrap();
+        }
+
+        writeln!(modules, "}}").unwrap();
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out.join("solver_modules.rs"), modules).unwrap();
+    fs::write(out.join("puzzle_tests.rs"), puzzle_tests(&src)).unwrap();
+}
+
+/// Create the tests of every day with a solver file, which has examples or a puzzle input in the
+/// input directory of the workspace.
+fn puzzle_tests(src: &Path) -> String {
+    let input_root = src.join("..").join("..").join("input");
+    let mut tests = String::new();
+
+    // Only an existing directory is watched, as cargo would run the build script every time otherwise
+    if input_root.is_dir() {
+        println!("cargo:rerun-if-changed={}", input_root.display());
+    }
+
+    for (year, year_dir) in numbered_entries(src, "y", "") {
+        writeln!(tests, "mod y{year} {{").unwrap();
+
+        for (day, _) in numbered_entries(&year_dir, "d", ".rs") {
+            let dir = input_root.join(year.to_string()).join(day.to_string());
+            writeln!(tests, "    mod d{day} {{").unwrap();
+
+            for part in ["a", "b"] {
+                let examples = example_files(&dir, part)
+                    .into_iter()
+                    .filter(|path| read_input_file(&dir, path).is_some())
+                    // The same names as the runner gives the examples
+                    .map(|path| path.strip_prefix(&dir).unwrap_or(&path).display().to_string());
+
+                for name in examples {
+                    let ident = name.trim_end_matches(".txt").replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+                    writeln!(tests, "        #[test]").unwrap();
+                    writeln!(tests, "        fn example_{part}_{ident}() {{").unwrap();
+                    writeln!(tests, "            crate::puzzle_tests::check_example({year}, {day}, crate::Part::{}, {name:?});", part.to_uppercase()).unwrap();
+                    writeln!(tests, "        }}").unwrap();
+                }
+
+                // Whether the solution is known is decided when the test loads the input
+                if read_input_file(&dir, "p").is_some() {
+                    writeln!(tests, "        #[test]").unwrap();
+                    writeln!(tests, "        fn puzzle_{part}() {{").unwrap();
+                    writeln!(tests, "            crate::puzzle_tests::check_puzzle({year}, {day}, crate::Part::{});", part.to_uppercase()).unwrap();
+                    writeln!(tests, "        }}").unwrap();
+                }
+            }
+
+            writeln!(tests, "    }}").unwrap();
+        }
+
+        writeln!(tests, "}}").unwrap();
+    }
+
+    tests
+}
+
+/// Every entry in the given directory whose name consists of the prefix, a number and the suffix,
+/// sorted by that number.
+fn numbered_entries(
+    dir: &Path,
+    prefix: &str,
+    suffix: &str,
+) -> Vec<(u16, PathBuf)> {
+    let mut entries = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter_map(|path| {
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse::<u16>()
+                .ok()?;
+            Some((number, path))
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
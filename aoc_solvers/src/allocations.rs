@@ -0,0 +1,174 @@
+//! Counting of the allocations of solver runs. The counting allocator is only the global allocator if the
+//! feature track-allocations is enabled, like with `cargo run --release --features track-allocations`, as
+//! counting slows down every allocation a bit.
+
+use serde::Serialize;
+
+/// Statistics about the memory a solver run allocated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct AllocationStats {
+    /// The most memory the run used at once in bytes, on top of the memory which was in use before it started
+    pub peak_bytes: usize,
+    /// How often memory was allocated or reallocated
+    pub allocations: usize,
+    /// The sum of the sizes of every allocation in bytes
+    pub allocated_bytes: usize,
+}
+
+/// Execute the given function and count its allocations. Returns no statistics if allocations are not tracked.
+///
+/// The counters are global, so allocations of other threads are counted as well, like of solvers which
+/// were abandoned after a timeout.
+#[cfg(feature = "track-allocations")]
+pub(crate) fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    use counting::{ALLOCATED_BYTES, ALLOCATIONS, CURRENT_BYTES, PEAK_BYTES};
+    use std::sync::atomic::Ordering::Relaxed;
+
+    let current = CURRENT_BYTES.load(Relaxed);
+    PEAK_BYTES.store(current, Relaxed);
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Relaxed);
+
+    let output = f();
+
+    let stats = AllocationStats {
+        peak_bytes: PEAK_BYTES.load(Relaxed).saturating_sub(current),
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Relaxed) - allocated_bytes,
+    };
+
+    (output, Some(stats))
+}
+
+/// Execute the given function. Allocations are not tracked without the feature track-allocations.
+#[cfg(not(feature = "track-allocations"))]
+pub(crate) fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    (f(), None)
+}
+
+// The allocator is only registered with the feature, but always tested
+#[cfg(any(test, feature = "track-allocations"))]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    /// The bytes which are currently allocated
+    pub static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+    /// The most bytes which were allocated at once since the last reset
+    pub static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+    pub static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    pub static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+    /// The system allocator, which counts every allocation.
+    pub struct CountingAllocator;
+
+    #[cfg(feature = "track-allocations")]
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocated(size: usize) {
+        let current = CURRENT_BYTES.fetch_add(size, Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Relaxed);
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(
+            &self,
+            layout: Layout,
+        ) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(
+            &self,
+            layout: Layout,
+        ) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+
+            ptr
+        }
+
+        unsafe fn dealloc(
+            &self,
+            ptr: *mut u8,
+            layout: Layout,
+        ) {
+            unsafe { System.dealloc(ptr, layout) };
+            CURRENT_BYTES.fetch_sub(layout.size(), Relaxed);
+        }
+
+        unsafe fn realloc(
+            &self,
+            ptr: *mut u8,
+            layout: Layout,
+            new_size: usize,
+        ) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+
+            // A reallocation frees the old memory and allocates the new one
+            if !new_ptr.is_null() {
+                CURRENT_BYTES.fetch_sub(layout.size(), Relaxed);
+                allocated(new_size);
+            }
+
+            new_ptr
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::allocations::counting::{CountingAllocator, ALLOCATED_BYTES, ALLOCATIONS, CURRENT_BYTES, PEAK_BYTES};
+    use std::alloc::{GlobalAlloc, Layout};
+    use std::sync::atomic::Ordering::Relaxed;
+
+    #[test]
+    fn the_allocator_counts_every_allocation() {
+        let layout = Layout::from_size_align(1000, 8).unwrap();
+        let allocations = ALLOCATIONS.load(Relaxed);
+        let allocated_bytes = ALLOCATED_BYTES.load(Relaxed);
+
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 1500);
+            assert!(PEAK_BYTES.load(Relaxed) >= 1500);
+            assert!(CURRENT_BYTES.load(Relaxed) >= 1500);
+            CountingAllocator.dealloc(ptr, Layout::from_size_align(1500, 8).unwrap());
+        }
+
+        // The allocator is the global one with the feature track-allocations, so other tests allocate as well
+        assert!(ALLOCATIONS.load(Relaxed) - allocations >= 2);
+        assert!(ALLOCATED_BYTES.load(Relaxed) - allocated_bytes >= 2500);
+    }
+
+    #[cfg(feature = "track-allocations")]
+    #[test]
+    fn allocations_are_counted() {
+        use crate::allocations::track;
+        use std::hint::black_box;
+
+        let (_, stats) = track(|| {
+            let first = black_box(vec![0u8; 1000]);
+            drop(first);
+            black_box(vec![0u8; 500])
+        });
+        let stats = stats.unwrap();
+
+        // Other tests run in parallel, so only the counters which never decrease can be checked exactly
+        assert!(stats.allocations >= 2);
+        assert!(stats.allocated_bytes >= 1500);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::registry::Part;
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The name of the answer log in the input directory of a day.
+const ANSWER_FILE: &str = "answers";
+
+/// What the advent of code website said about a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+/// The answers which were submitted for a day, with one answer per line, like `a too_high 1234`.
+/// Lines starting with # are ignored.
+#[derive(Default)]
+pub(crate) struct AnswerLog {
+    entries: Vec<(Part, Verdict, String)>,
+}
+
+impl AnswerLog {
+    /// Load the answer log from the given input directory of a day. Returns an empty log if there is none.
+    /// Fails if any of its lines is invalid.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let Ok(content) = read_to_string(dir.join(ANSWER_FILE)) else {
+            return Ok(AnswerLog::default());
+        };
+
+        let entries = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| parse_entry(line).map_err(|e| format!("invalid line '{line}' in the answer log: {e}")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(AnswerLog { entries })
+    }
+
+    /// The answer of the given part which is known to be correct.
+    pub fn correct(
+        &self,
+        part: Part,
+    ) -> Option<String> {
+        self.answers(part)
+            .find(|(verdict, _)| *verdict == Verdict::Correct)
+            .map(|(_, answer)| answer.to_string())
+    }
+
+    /// Check the given output of a part against the submitted answers. Returns the reason if the output
+    /// is known to be wrong, because it was already rejected or is not within the bounds of the too high
+    /// and too low answers.
+    pub fn check(
+        &self,
+        part: Part,
+        output: &str,
+    ) -> Option<String> {
+        let numeric_output = output.parse::<i128>().ok();
+
+        self.answers(part).find_map(|(verdict, answer)| {
+            let numeric_answer = answer.parse::<i128>().ok();
+
+            match (verdict, numeric_output.zip(numeric_answer)) {
+                (Verdict::Wrong, _) if answer == output => Some(format!("{output} was already rejected")),
+                (Verdict::TooHigh, Some((output, answer))) if output >= answer => {
+                    Some(format!("{output} is not lower than {answer}, which is too high"))
+                }
+                (Verdict::TooLow, Some((output, answer))) if output <= answer => {
+                    Some(format!("{output} is not higher than {answer}, which is too low"))
+                }
+                _ => None,
+            }
+        })
+    }
+
+    fn answers(
+        &self,
+        part: Part,
+    ) -> impl Iterator<Item = (Verdict, &str)> {
+        self.entries
+            .iter()
+            .filter(move |(p, _, _)| *p == part)
+            .map(|(_, verdict, answer)| (*verdict, answer.as_str()))
+    }
+}
+
+fn parse_entry(line: &str) -> Result<(Part, Verdict, String), String> {
+    let mut split = line.splitn(3, char::is_whitespace);
+    let (Some(part), Some(verdict), Some(answer)) = (split.next(), split.next(), split.next()) else {
+        return Err("expected <part> <verdict> <answer>".to_string());
+    };
+
+    Ok((part.parse()?, verdict.parse()?, answer.trim().to_string()))
+}
+
+/// Record the given answer of a part in the answer log in the input directory of the day.
+pub fn record_answer(
+    input_root: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+    verdict: Verdict,
+) -> Result<(), String> {
+    let dir = input_root.join(year.to_string()).join(day.to_string());
+
+    if !dir.is_dir() {
+        return Err(format!("The input directory {} does not exist", dir.display()));
+    }
+
+    let path = dir.join(ANSWER_FILE);
+    let part = match part {
+        Part::A => "a",
+        Part::B => "b",
+    };
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{part} {verdict} {}", answer.trim()))
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "a" => Ok(Part::A),
+            "b" => Ok(Part::B),
+            _ => Err(format!("'{s}' is not a valid part, expected a or b")),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            _ => Err(format!("'{s}' is not a valid verdict, expected correct, wrong, too_high or too_low")),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+        };
+        write!(f, "{verdict}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{parse_entry, AnswerLog};
+    use crate::registry::Part;
+
+    #[test]
+    fn outputs_are_checked_against_the_log() {
+        let log = AnswerLog {
+            entries: ["a wrong 42", "a too_high 500", "a too-low 100", "b correct hello world"]
+                .into_iter()
+                .map(|line| parse_entry(line).unwrap())
+                .collect(),
+        };
+
+        assert_eq!(log.check(Part::A, "42"), Some("42 was already rejected".to_string()));
+        assert_eq!(log.check(Part::A, "500"), Some("500 is not lower than 500, which is too high".to_string()));
+        assert_eq!(log.check(Part::A, "99"), Some("99 is not higher than 100, which is too low".to_string()));
+        assert_eq!(log.check(Part::A, "300"), None);
+        assert_eq!(log.check(Part::B, "42"), None);
+        assert_eq!(log.correct(Part::A), None);
+        assert_eq!(log.correct(Part::B), Some("hello world".to_string()));
+        assert!(parse_entry("c wrong 1").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::params::Params;
+use crate::registry::Part;
+use std::fmt::Display;
+
+/// Information about the example or puzzle a solver runs on. Solvers receive it if they take it as
+/// their second argument, like `fn solve_a(input: &str, context: &PuzzleContext) -> usize`.
+pub struct PuzzleContext {
+    pub(crate) part: Part,
+    /// The name of the example, or None if this is the real puzzle
+    pub(crate) example: Option<String>,
+    pub(crate) params: Params,
+}
+
+impl PuzzleContext {
+    /// The part the solver runs for.
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    /// Tells if the solver runs on an example instead of the real puzzle input.
+    pub fn is_example(&self) -> bool {
+        self.example.is_some()
+    }
+
+    /// The name of the example file the solver runs on, like ea or ea2. None for the real puzzle.
+    pub fn example_name(&self) -> Option<&str> {
+        self.example.as_deref()
+    }
+
+    /// The parameters of the example or puzzle.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Write the given debug message with [helpers::debug], prefixed with the example or puzzle the solver runs on.
+    pub fn debug(
+        &self,
+        message: impl Display,
+    ) {
+        helpers::debug!("{} {message}", self.label());
+    }
+
+    fn label(&self) -> String {
+        let part = match self.part {
+            Part::A => "a",
+            Part::B => "b",
+        };
+
+        match &self.example {
+            Some(example) => format!("[{part} {example}]"),
+            None => format!("[{part} puzzle]"),
+        }
+    }
+}
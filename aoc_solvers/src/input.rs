@@ -0,0 +1,190 @@
+use crate::answers::AnswerLog;
+use crate::input_files::{example_files, read_input_file};
+use crate::meta::DayMeta;
+use crate::params::Params;
+use crate::registry::Part;
+use itertools::Itertools;
+use std::path::Path;
+
+/// The inputs and expected results of a puzzle, loaded from its input directory.
+pub(crate) struct Input {
+    /// Input of the main puzzle
+    pub puzzle_input: Option<String>,
+    /// Parameters of the main puzzle, from the file params and the metadata
+    pub puzzle_params: Params,
+    /// Every example of part A
+    pub examples_a: Vec<Example>,
+    /// Every example of part B
+    pub examples_b: Vec<Example>,
+    /// Existing solution for A. Helpful to automatically check if solvers still work.
+    pub solution_a: Option<String>,
+    /// Existing solution for B
+    pub solution_b: Option<String>,
+    /// Information about the day, from the file meta.toml
+    pub meta: DayMeta,
+    /// The answers which were already submitted, from the file answers
+    pub answers: AnswerLog,
+}
+
+impl Input {
+    /// Load the input of the given day from its directory <input root>/<year>/<day>/. Fails if any of its
+    /// files is invalid, like a meta.toml which is no valid TOML.
+    pub fn load(
+        input_root: &Path,
+        day: u8,
+        year: u16,
+    ) -> Result<Self, String> {
+        let dir = input_root.join(year.to_string()).join(day.to_string());
+        let meta = read_input_file(&dir, "meta.toml")
+            .map(|meta| DayMeta::parse(&meta).map_err(|e| format!("invalid meta.toml: {e}")))
+            .transpose()?
+            .unwrap_or_default();
+        let puzzle_input = read_input_file(&dir, "p");
+        let puzzle_params = read_input_file(&dir, "params")
+            .map(|params| Params::parse(&params).map_err(|e| format!("invalid puzzle parameters: {e}")))
+            .transpose()?
+            .unwrap_or_default();
+        let puzzle_params = meta.puzzle_params().merged(puzzle_params);
+        let examples_a = Self::load_examples(&dir, "a", &meta)?;
+        let examples_b = Self::load_examples(&dir, "b", &meta)?;
+        // The solutions of the metadata take precedence over the older s file
+        let (s_a, s_b) = read_input_file(&dir, "s")
+            .map(Self::parse_text_to_given_solution)
+            .unwrap_or_default();
+        let (meta_a, meta_b) = meta.answers();
+        let answers = AnswerLog::load(&dir)?;
+
+        Ok(Input {
+            puzzle_input,
+            puzzle_params,
+            examples_a,
+            examples_b,
+            solution_a: meta_a.or(s_a),
+            solution_b: meta_b.or(s_b),
+            meta,
+            answers,
+        })
+    }
+
+    /// The solution of the given part. An answer which was accepted on the website is as good as a solution.
+    pub fn expected(
+        &self,
+        part: Part,
+    ) -> Option<String> {
+        let solution = match part {
+            Part::A => &self.solution_a,
+            Part::B => &self.solution_b,
+        };
+
+        solution.clone().or_else(|| self.answers.correct(part))
+    }
+
+    /// Load every example of the given part (a or b), in the order of [example_files].
+    ///
+    /// Every example can start with a header of parameters, like `# width=11 height=7`. They take
+    /// precedence over the parameters of the example in the metadata.
+    fn load_examples(
+        dir: &Path,
+        part: &str,
+        meta: &DayMeta,
+    ) -> Result<Vec<Example>, String> {
+        example_files(dir, part)
+            .into_iter()
+            .filter_map(|path| {
+                let content = read_input_file(dir, &path)?;
+                let name = path.strip_prefix(dir).unwrap_or(&path).display().to_string();
+
+                Some(Self::parse_example(name, &content, meta))
+            })
+            .collect()
+    }
+
+    /// Parse the example file of the given name, with its header of parameters and its expected result.
+    fn parse_example(
+        name: String,
+        content: &str,
+        meta: &DayMeta,
+    ) -> Result<Example, String> {
+        let (params, content) =
+            Params::split_header(content).map_err(|e| format!("invalid parameters in example {name}: {e}"))?;
+        let (input, expected) =
+            Self::parse_example_input(content.to_string()).map_err(|e| format!("invalid example {name}: {e}"))?;
+        let params = meta.example_params(&name).merged(params);
+
+        Ok(Example {
+            name,
+            input,
+            expected,
+            params,
+        })
+    }
+
+    /// Split an example into its input and expected result. The expected result is either fenced by
+    /// lines of ---, which allows answers containing empty lines or starting with #, or it is the first
+    /// block before an empty line.
+    fn parse_example_input(s: String) -> Result<(String, String), String> {
+        if let Some(fenced) = s.strip_prefix("---\n") {
+            let (result, text) = fenced
+                .split_once("\n---\n")
+                .or_else(|| fenced.strip_suffix("\n---").map(|result| (result, "")))
+                .ok_or("a fenced result must be closed by a line of ---")?;
+
+            return Ok((text.to_string(), result.to_string()));
+        }
+
+        let mut split = s.split("\n\n");
+
+        // the example solution is the first block before the double new line
+        let result = split
+            .next()
+            .expect("Result and input must be separated by one double new line")
+            .to_string();
+        // join the remaining block(s) back together using double new lines again
+        let text = split.join("\n\n");
+
+        Ok((text, result))
+    }
+
+    /// Parse the solutions of the s file, which are separated by an empty line. A day with only one part,
+    /// like day 25, has only the solution of part A.
+    fn parse_text_to_given_solution(s: String) -> (Option<String>, Option<String>) {
+        let mut solutions = s
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|solution| !solution.is_empty())
+            .map(str::to_string);
+
+        (solutions.next(), solutions.next())
+    }
+}
+
+/// An example of a part, like ea2 or examples/a/small.txt.
+pub(crate) struct Example {
+    /// The path of the example file, relative to the input directory of the day
+    pub name: String,
+    pub input: String,
+    pub expected: String,
+    pub params: Params,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::Input;
+
+    #[test]
+    fn expected_results_can_be_fenced() {
+        let (input, expected) = Input::parse_example_input("---\n##..\n\n#..#\n---\naddx 15\nnoop\n".to_string()).unwrap();
+        assert_eq!(expected, "##..\n\n#..#");
+        assert_eq!(input, "addx 15\nnoop\n");
+
+        let (input, expected) = Input::parse_example_input("---\n#.\n.#\n---".to_string()).unwrap();
+        assert_eq!(expected, "#.\n.#");
+        assert_eq!(input, "");
+
+        let (input, expected) = Input::parse_example_input("13\n\n1,2\n\n3,4".to_string()).unwrap();
+        assert_eq!(expected, "13");
+        assert_eq!(input, "1,2\n\n3,4");
+
+        assert!(Input::parse_example_input("---\n13\n\n1,2".to_string()).is_err());
+    }
+}
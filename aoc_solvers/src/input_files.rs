@@ -0,0 +1,62 @@
+//! The files in the input directory of a day. The build script includes this module to find the examples
+//! it generates tests for, so it only uses the standard library.
+
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// The example files of the given part (a or b) in the input directory of a day, which may not exist or
+/// be empty. These are, in this order:
+/// - the file e<part>, like ea
+/// - the numbered files e<part><number>, like ea1 and ea2, sorted by their number
+/// - the files examples/<part>/*.txt, sorted by their name
+pub(crate) fn example_files(
+    dir: &Path,
+    part: &str,
+) -> Vec<PathBuf> {
+    let prefix = format!("e{part}");
+    let mut numbered = files_in(dir)
+        .filter_map(|path| {
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(&prefix)?
+                .parse::<usize>()
+                .ok()?;
+            Some((number, path))
+        })
+        .collect::<Vec<_>>();
+    numbered.sort();
+    let mut example_files = files_in(&dir.join("examples").join(part))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect::<Vec<_>>();
+    example_files.sort();
+
+    [dir.join(&prefix)]
+        .into_iter()
+        .chain(numbered.into_iter().map(|(_, path)| path))
+        .chain(example_files)
+        .collect()
+}
+
+/// Read the given file from the input directory of a day. Returns None if the file does not exist
+/// or is empty, so files created in advance (like by the new command) can be filled in later.
+pub(crate) fn read_input_file(
+    dir: &Path,
+    file: impl AsRef<Path>,
+) -> Option<String> {
+    read_to_string(dir.join(file))
+        .ok()
+        .filter(|content| !content.trim().is_empty())
+        // Unify the line endings to resolve regex issues
+        .map(|content| content.replace("\r\n", "\n"))
+}
+
+/// Every file in the given directory. Returns nothing if the directory does not exist.
+fn files_in(dir: &Path) -> impl Iterator<Item = PathBuf> {
+    read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+}
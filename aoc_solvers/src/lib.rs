@@ -1,239 +1,665 @@
+use crate::answers::AnswerLog;
+use crate::input::{Example, Input};
+use crate::measure::{measure, Run};
+use crate::panic::isolate;
+use crate::registry::{ParsedInput, SolverEntry, SolverKind};
+use crate::timeout::with_timeout;
+use helpers::trace::with_scope;
 use itertools::Itertools;
-use std::fmt::{Debug, Display};
-use std::fs::read_to_string;
-use std::str::FromStr;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::successors;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
 
-mod y2022;
-mod y2023;
-mod y2024;
-mod y2025;
+pub use crate::allocations::AllocationStats;
+pub use crate::answers::{record_answer, Verdict};
+pub use crate::context::PuzzleContext;
+pub use crate::measure::{BenchStats, Measured};
+pub use crate::meta::DayMeta;
+pub use crate::params::Params;
+pub use crate::registry::{implemented_days, implemented_years, solvers, Part, Solver, SolverInfo};
+pub use crate::report::{Report, ReportEntry, ReportPart, ReportStatus};
 
+mod allocations;
+mod answers;
+mod context;
+mod input;
+mod input_files;
+mod measure;
+mod meta;
+mod panic;
+mod params;
+#[cfg(test)]
+mod puzzle_tests;
+mod registry;
+mod report;
+mod timeout;
+
+// The year modules and their day modules (src/y<year>/d<day>.rs) are generated by the build script
+include!(concat!(env!("OUT_DIR"), "/solver_modules.rs"));
+
+/// Every day an advent of code puzzle can exist for.
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// Run the examples and puzzles of the given day. Returns None if no solver is registered for it.
 pub fn solve(
     day: u8,
     year: u16,
     run_config: RunConfig,
 ) -> Option<PuzzleResult> {
-    match year {
-        2022 => y2022::solve(day, run_config),
-        2023 => y2023::solve(day, run_config),
-        2024 => y2024::solve(day, run_config),
-        2025 => y2025::solve(day, run_config),
-        _ => None,
+    let a_entry = registry::find(year, day, Part::A);
+    let b_entry = registry::find(year, day, Part::B);
+
+    if a_entry.is_none() && b_entry.is_none() {
+        return None;
+    }
+
+    match Input::load(&run_config.input_root, day, year) {
+        Ok(input) => Some(solve_puzzle(run_config, input, a_entry, b_entry)),
+        Err(e) => Some(PuzzleResult::invalid_input(e, a_entry, b_entry)),
+    }
+}
+
+/// Run the solver of the given part with the given input, without reading any files. The solver gets the
+/// default parameters, see [run_with_params] to set them. Returns the rendered answer.
+///
+/// ```no_run
+/// let answer = aoc_solvers::run(2024, 17, aoc_solvers::Part::A, "Register A: 729\n...");
+/// ```
+pub fn run(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &str,
+) -> Result<String, RunError> {
+    run_with_params(year, day, part, input, &Params::default())
+}
+
+/// Run the solver of the given part with the given input and parameters, like the size of a board which only
+/// the examples set (`"width=11 height=7".parse()`). The solver runs on the current thread without a timeout.
+/// A panic of the solver is returned as an error.
+pub fn run_with_params(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &str,
+    params: &Params,
+) -> Result<String, RunError> {
+    let entry = registry::find(year, day, part).ok_or(RunError::NoSolver { year, day, part })?;
+    let execution = Execution {
+        solver: entry.solver,
+        year,
+        day,
+        part,
+        repetitions: 1,
+        timeout: None,
+    };
+
+    let parsing = execution.parse(input);
+    let run = parsed_input(parsing.as_ref())
+        .and_then(|parsed| execution.run(input, parsed.map(|parsed| &parsed.output), None, params))
+        .map_err(|interruption| match interruption {
+            Interruption::Panicked(message) => RunError::Panicked { message },
+            Interruption::TimedOut(_) => unreachable!("the solver runs without a timeout"),
+        })?;
+
+    classify(
+        run,
+        None,
+        Err(RunError::NotImplemented),
+        |chain| Err(RunError::Failed { chain }),
+        |answer| Ok(answer.to_string()),
+    )
+    .result
+}
+
+/// Why [run] did not return an answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RunError {
+    /// No solver is registered for the part.
+    NoSolver { year: u16, day: u8, part: Part },
+    /// The solver returned [NotImplemented].
+    NotImplemented,
+    /// The solver returned an error. Contains the messages of the error and all of its sources.
+    Failed { chain: Vec<String> },
+    /// The solver panicked. Contains the panic message and its location.
+    Panicked { message: String },
+}
+
+impl Display for RunError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            RunError::NoSolver { year, day, part } => {
+                write!(f, "no solver exists for part {part:?} of year {year} and day {day}")
+            }
+            RunError::NotImplemented => write!(f, "the solver is not implemented yet"),
+            RunError::Failed { chain } => write!(f, "the solver returned an error: {}", chain.join(": ")),
+            RunError::Panicked { message } => write!(f, "the solver panicked: {message}"),
+        }
     }
 }
 
-pub(crate) fn solve_puzzle<
-    A: PuzzleOutput,
-    B: PuzzleOutput,
-    AS: Fn(&str) -> A,
-    BS: Fn(&str) -> B,
->(
+impl Error for RunError {}
+
+/// Tells if a solver exists for the given year and day.
+pub fn solver_exists(
+    day: u8,
+    year: u16,
+) -> bool {
+    registry::find(year, day, Part::A).is_some() || registry::find(year, day, Part::B).is_some()
+}
+
+/// Run the selected examples and puzzles with the given solvers. A part without a solver is not executed.
+fn solve_puzzle(
     run_config: RunConfig,
-    input: Input<A, B>,
-    a_solver: AS,
-    b_solver: BS,
+    input: Input,
+    a_entry: Option<&SolverEntry>,
+    b_entry: Option<&SolverEntry>,
 ) -> PuzzleResult {
-    let ea_res = if run_config.run_example_a {
-        Some(match input.example_a {
-            None => ExampleResult::DoesNotExist,
-            Some((text, expectation)) => {
-                let result = a_solver(&text);
-
-                if expectation == result {
-                    ExampleResult::Works
-                } else {
-                    ExampleResult::Fails {
-                        expected: expectation.to_string(),
-                        was: result.to_string(),
-                    }
-                }
-            }
-        })
-    } else {
-        None
+    let expected_a = input.expected(Part::A);
+    let expected_b = input.expected(Part::B);
+    let execution = |entry: &SolverEntry| Execution {
+        solver: entry.solver,
+        year: entry.year,
+        day: entry.day,
+        part: entry.part,
+        repetitions: run_config.repetitions,
+        timeout: run_config.timeout_for(entry),
     };
 
-    let eb_res = if run_config.run_example_b {
-        Some(match input.example_b {
-            None => ExampleResult::DoesNotExist,
-            Some((text, expectation)) => {
-                let result = b_solver(&text);
-
-                if expectation == result {
-                    ExampleResult::Works
-                } else {
-                    ExampleResult::Fails {
-                        expected: expectation.to_string(),
-                        was: result.to_string(),
-                    }
-                }
+    // Both parts of a Solver share the parsed puzzle input, so it is only parsed once
+    let puzzle_parsing = input.puzzle_input.as_deref().and_then(|text| {
+        [a_entry.filter(|_| run_config.run_a), b_entry.filter(|_| run_config.run_b)]
+            .into_iter()
+            .flatten()
+            .find_map(|entry| execution(entry).parse(text))
+    });
+
+    PuzzleResult {
+        example_a_results: a_entry
+            .filter(|_| run_config.run_example_a)
+            .map(|entry| run_examples(input.examples_a, "ea", execution(entry)))
+            .unwrap_or_default(),
+        a_result: a_entry
+            .filter(|_| run_config.run_a)
+            .map(|entry| {
+                run_real(
+                    input.puzzle_input.as_deref(),
+                    expected_a,
+                    &input.puzzle_params,
+                    &input.answers,
+                    puzzle_parsing.as_ref(),
+                    execution(entry),
+                )
+            }),
+        example_b_results: b_entry
+            .filter(|_| run_config.run_example_b)
+            .map(|entry| run_examples(input.examples_b, "eb", execution(entry)))
+            .unwrap_or_default(),
+        b_result: b_entry
+            .filter(|_| run_config.run_b)
+            .map(|entry| {
+                run_real(
+                    input.puzzle_input.as_deref(),
+                    expected_b,
+                    &input.puzzle_params,
+                    &input.answers,
+                    puzzle_parsing.as_ref(),
+                    execution(entry),
+                )
+            }),
+        meta: input.meta,
+    }
+}
+
+/// How a solver is executed.
+struct Execution {
+    solver: SolverKind,
+    year: u16,
+    day: u8,
+    part: Part,
+    repetitions: usize,
+    timeout: Option<Duration>,
+}
+
+/// The answer of a solver and the measurements of its runs.
+type Measurement = Run<Box<dyn Answer>>;
+
+/// The parsed input of a [Solver] with the measurements of parsing it, or why the parser did not return it.
+type Parsing = Result<Run<ParsedInput>, Interruption>;
+
+/// Why a solver did not return an answer.
+#[derive(Clone)]
+enum Interruption {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Execution {
+    /// Parse the given input, if the solver is a part of a [Solver]. Returns None for solver functions,
+    /// which parse the input themselves.
+    fn parse(
+        &self,
+        input: &str,
+    ) -> Option<Parsing> {
+        let SolverKind::Parsed { parser, .. } = self.solver else {
+            return None;
+        };
+        let input = input.to_string();
+
+        Some(self.execute(1, move || parser(&input)))
+    }
+
+    /// Run the solver with the given input and measure it. The parsed input is required by the parts of a
+    /// [Solver], which do not receive the text. The example is None for the real puzzle.
+    fn run(
+        &self,
+        input: &str,
+        parsed: Option<&ParsedInput>,
+        example: Option<&str>,
+        params: &Params,
+    ) -> Result<Measurement, Interruption> {
+        let context = PuzzleContext {
+            part: self.part,
+            example: example.map(str::to_string),
+            params: params.clone(),
+        };
+
+        match self.solver {
+            SolverKind::Function(solver) => {
+                let input = input.to_string();
+                self.execute(self.repetitions, move || solver(&input, &context))
+            }
+            SolverKind::Parsed { solver, .. } => {
+                let parsed = parsed.expect("the input of a Solver should be parsed").clone();
+                self.execute(self.repetitions, move || solver(&parsed, &context))
+            }
+        }
+    }
+
+    /// Execute the given function the given amount of times and measure it. Messages are traced in the scope
+    /// <year>/<day>. If there is a timeout, the function runs on a worker thread, which is abandoned once the
+    /// timeout passed for every repetition.
+    fn execute<T: Send + 'static>(
+        &self,
+        repetitions: usize,
+        f: impl Fn() -> T + Send + 'static,
+    ) -> Result<Run<T>, Interruption> {
+        let scope = format!("{}/{}", self.year, self.day);
+        let run = move || with_scope(&scope, || isolate(|| measure(repetitions, &f)));
+
+        let result = match self.timeout {
+            Some(timeout) => {
+                let timeout = timeout.saturating_mul(repetitions.try_into().unwrap_or(u32::MAX));
+                with_timeout(timeout, run).ok_or(Interruption::TimedOut(timeout))?
             }
+            None => run(),
+        };
+
+        result.map_err(Interruption::Panicked)
+    }
+}
+
+/// Run every given example. If there are none, a single missing example with the given default name is returned.
+fn run_examples(
+    examples: Vec<Example>,
+    default_name: &str,
+    execution: Execution,
+) -> Vec<ExampleRun> {
+    if examples.is_empty() {
+        return vec![ExampleRun {
+            name: default_name.to_string(),
+            measured: Measured::unmeasured(ExampleResult::DoesNotExist),
+        }];
+    }
+
+    examples
+        .into_iter()
+        .map(|example| ExampleRun {
+            measured: run_example(&example, &execution),
+            name: example.name,
         })
-    } else {
-        None
+        .collect()
+}
+
+fn run_example(
+    example: &Example,
+    execution: &Execution,
+) -> Measured<ExampleResult> {
+    let parsing = execution.parse(&example.input);
+    let measurement = parsed_input(parsing.as_ref()).and_then(|parsed| {
+        let input = parsed.map(|parsed| &parsed.output);
+        let measurement = execution.run(&example.input, input, Some(&example.name), &example.params)?;
+        Ok((measurement, parsed))
+    });
+    let (measurement, parsed) = match measurement {
+        Ok(measurement) => measurement,
+        Err(Interruption::Panicked(message)) => {
+            return Measured::unmeasured(ExampleResult::Panicked { message });
+        }
+        Err(Interruption::TimedOut(after)) => {
+            return Measured::timed_out(ExampleResult::TimedOut { after }, after);
+        }
     };
 
-    let a_res = if run_config.run_a {
-        Some(match &input.puzzle_input {
-            None => RealResult::DoesNotExist,
-            Some(text) => {
-                let solution_a = a_solver(text);
-
-                match &input.puzzle_solution {
-                    Some((expected_a, _)) => {
-                        if &solution_a == expected_a {
-                            RealResult::Works(solution_a.to_string())
-                        } else {
-                            RealResult::Fails {
-                                expected: expected_a.to_string(),
-                                was: solution_a.to_string(),
-                            }
-                        }
-                    }
-                    None => RealResult::Output(solution_a.to_string()),
+    classify(
+        measurement,
+        parsed,
+        ExampleResult::NotImplemented,
+        |chain| ExampleResult::Failed { chain },
+        |output| {
+            if output.matches(&example.expected) {
+                ExampleResult::Works(output.to_string())
+            } else {
+                ExampleResult::Fails {
+                    expected: example.expected.clone(),
+                    was: output.to_string(),
                 }
             }
-        })
-    } else {
-        None
+        },
+    )
+}
+
+fn run_real(
+    puzzle_input: Option<&str>,
+    expected: Option<String>,
+    params: &Params,
+    answers: &AnswerLog,
+    parsing: Option<&Parsing>,
+    execution: Execution,
+) -> Measured<RealResult> {
+    let Some(text) = puzzle_input else {
+        return Measured::unmeasured(RealResult::DoesNotExist);
     };
 
-    let b_res = if run_config.run_b {
-        Some(match &input.puzzle_input {
-            None => RealResult::DoesNotExist,
-            Some(text) => {
-                let solution_b = b_solver(text);
-
-                match &input.puzzle_solution {
-                    Some((_, expected_b)) => {
-                        if &solution_b == expected_b {
-                            RealResult::Works(solution_b.to_string())
-                        } else {
-                            RealResult::Fails {
-                                expected: expected_b.to_string(),
-                                was: solution_b.to_string(),
-                            }
-                        }
-                    }
-                    None => RealResult::Output(solution_b.to_string()),
+    let measurement = parsed_input(parsing).and_then(|parsed| {
+        let measurement = execution.run(text, parsed.map(|parsed| &parsed.output), None, params)?;
+        Ok((measurement, parsed))
+    });
+    let (measurement, parsed) = match measurement {
+        Ok(measurement) => measurement,
+        Err(Interruption::Panicked(message)) => {
+            return Measured::unmeasured(RealResult::Panicked { message });
+        }
+        Err(Interruption::TimedOut(after)) => {
+            return Measured::timed_out(RealResult::TimedOut { after }, after);
+        }
+    };
+
+    classify(
+        measurement,
+        parsed,
+        RealResult::NotImplemented,
+        |chain| RealResult::Failed { chain },
+        |output| match expected {
+            Some(expected) if output.matches(&expected) => RealResult::Works(output.to_string()),
+            Some(expected) => RealResult::Fails {
+                expected,
+                was: output.to_string(),
+            },
+            None => {
+                let output = output.to_string();
+                match answers.check(execution.part, &output) {
+                    Some(reason) => RealResult::KnownWrong { was: output, reason },
+                    None => RealResult::Output(output),
                 }
             }
-        })
-    } else {
-        None
+        },
+    )
+}
+
+/// Turn the answer of a run into the result of the given kind. An answer which is [NotImplemented] is not
+/// measured, a [Failure] is passed to the failed function with its messages and every other answer is checked
+/// by the given function.
+fn classify<R>(
+    measurement: Measurement,
+    parsed: Option<&Run<ParsedInput>>,
+    not_implemented: R,
+    failed: impl FnOnce(Vec<String>) -> R,
+    check: impl FnOnce(Box<dyn Answer>) -> R,
+) -> Measured<R> {
+    let Run {
+        output,
+        duration,
+        bench,
+        allocations,
+    } = measurement;
+
+    // The box implements Answer itself, so the answer inside of it has to be checked
+    let answer = (*output).as_any();
+
+    if answer.is::<NotImplemented>() {
+        return Measured::unmeasured(not_implemented);
+    }
+
+    let result = match answer.downcast_ref::<Failure>() {
+        Some(failure) => failed(failure.chain.clone()),
+        None => check(output),
     };
 
-    PuzzleResult {
-        example_a_result: ea_res,
-        a_result: a_res,
-        example_b_result: eb_res,
-        b_result: b_res,
+    Measured {
+        result,
+        duration: Some(duration),
+        parse_duration: parsed.map(|parsed| parsed.duration),
+        bench,
+        allocations,
+        parse_allocations: parsed.and_then(|parsed| parsed.allocations),
+    }
+}
+
+/// The parsed input with the measurements of parsing it, if the solver is a part of a [Solver].
+fn parsed_input(parsing: Option<&Parsing>) -> Result<Option<&Run<ParsedInput>>, Interruption> {
+    parsing.map(|parsing| parsing.as_ref().map_err(Interruption::clone)).transpose()
+}
+
+/// The answer of a solver which does not solve its part yet. The runner reports it as not implemented
+/// instead of comparing it with the expected result.
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+/// The error a fallible solver returned, as the messages of the error and all of its sources.
+pub(crate) struct Failure {
+    chain: Vec<String>,
+}
+
+impl Failure {
+    pub(crate) fn new(error: &(dyn Error + 'static)) -> Self {
+        Failure {
+            chain: successors(Some(error), |&error| error.source())
+                .map(ToString::to_string)
+                .collect(),
+        }
     }
 }
 
-trait PuzzleOutput: Display + PartialEq + FromStr<Err: Debug> {}
+impl Display for Failure {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.chain.join(": "))
+    }
+}
+
+/// The type erased output of a solver, which can be compared with an expected result.
+/// It is sent back from the worker thread the solver runs on.
+pub(crate) trait Answer: Display + Send {
+    /// Tells if this answer equals the given expected result. They are compared by their rendered
+    /// strings, ignoring whitespace at the end of every line and trailing empty lines.
+    fn matches(
+        &self,
+        expected: &str,
+    ) -> bool {
+        normalize(&self.to_string()) == normalize(expected)
+    }
+
+    /// The answer as [Any], to tell if it is [NotImplemented] or a [Failure].
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Display + Send + 'static> Answer for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
-impl<T> PuzzleOutput for T where T: Display + PartialEq + FromStr<Err: Debug> {}
+/// Remove the whitespace at the end of every line and the trailing empty lines of the given answer. A result
+/// matches its expected result if both are the same after normalizing them.
+pub fn normalize(answer: &str) -> String {
+    answer
+        .lines()
+        .map(str::trim_end)
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
 
+#[derive(Clone)]
 pub struct RunConfig {
     pub run_example_a: bool,
     pub run_a: bool,
     pub run_example_b: bool,
     pub run_b: bool,
+    /// How often every executed solver runs. Values greater than 1 create benchmark statistics.
+    pub repetitions: usize,
+    /// How long a single run of a solver may take before it is abandoned and reported as timed out.
+    /// A timeout set on the solver itself takes precedence. None disables all timeouts.
+    pub timeout: Option<Duration>,
+    /// The directory which contains the input directory <year>/<day>/ of every puzzle.
+    pub input_root: PathBuf,
 }
 
-struct Input<A: PuzzleOutput, B: PuzzleOutput> {
-    /// Input of the main puzzle
-    pub puzzle_input: Option<String>,
-    /// Input and expected result of example A, if present
-    pub example_a: Option<(String, A)>,
-    /// Input and expected result of example B, if present
-    pub example_b: Option<(String, B)>,
-    /// Existing solutions for A and B. Helpful to automatically check if solvers still work.
-    pub puzzle_solution: Option<(A, B)>,
+impl RunConfig {
+    /// The timeout of the given solver, which is its own timeout if it has one and the default otherwise.
+    fn timeout_for(
+        &self,
+        entry: &SolverEntry,
+    ) -> Option<Duration> {
+        self.timeout.map(|default| entry.timeout.unwrap_or(default))
+    }
 }
 
-impl<A: PuzzleOutput, B: PuzzleOutput> Input<A, B> {
-    fn load(
-        day: u8,
-        year: u16,
-    ) -> Self {
-        let puzzle_input = read_to_string(format!("./input/{year}/{day}/p"))
-            .ok()
-            .map(|s| s.replace("\r\n", "\n")); // replace line endings to resolve regex issues
-        let example_a = read_to_string(format!("./input/{year}/{day}/ea"))
-            .ok()
-            .map(Self::parse_example_input::<A>);
-
-        let example_b = read_to_string(format!("./input/{year}/{day}/eb"))
-            .ok()
-            .map(Self::parse_example_input::<B>);
-
-        let puzzle_solution = match read_to_string(format!("./input/{year}/{day}/s")) {
-            Ok(text) => Some(Self::parse_text_to_given_solution(text)),
-            Err(_) => None,
-        };
-
-        Input {
-            puzzle_input,
-            example_a,
-            example_b,
-            puzzle_solution,
+impl Default for RunConfig {
+    /// Run every example and puzzle once without a timeout, using the input directory of the workspace.
+    fn default() -> Self {
+        RunConfig {
+            run_example_a: true,
+            run_a: true,
+            run_example_b: true,
+            run_b: true,
+            repetitions: 1,
+            timeout: None,
+            input_root: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../input")),
         }
     }
+}
 
-    fn parse_example_input<T: PuzzleOutput>(s: String) -> (String, T) {
-        // Unify the line endings
-        let s = s.replace("\r\n", "\n");
-        let mut split = s.split("\n\n");
+pub struct PuzzleResult {
+    /// The results of every example of part A. Empty if the examples were not executed.
+    pub example_a_results: Vec<ExampleRun>,
+    pub a_result: Option<Measured<RealResult>>,
+    /// The results of every example of part B. Empty if the examples were not executed.
+    pub example_b_results: Vec<ExampleRun>,
+    pub b_result: Option<Measured<RealResult>>,
+    /// Information about the day, like its title
+    pub meta: DayMeta,
+}
 
-        // the example solution is the first block before the double new line
-        let result = split
-            .next()
-            .expect("Result and input must be separated by one double new line")
-            .parse::<T>()
-            .expect("first line should be the expected result");
-        // join the remaining block(s) back together using double new lines again
-        let text = split.join("\n\n");
+impl PuzzleResult {
+    /// The result of a day whose input directory could not be loaded, like because of an invalid meta.toml.
+    /// Every part with a solver failed with the given error, without running it.
+    fn invalid_input(
+        error: String,
+        a_entry: Option<&SolverEntry>,
+        b_entry: Option<&SolverEntry>,
+    ) -> Self {
+        let failed = |entry: Option<&SolverEntry>| {
+            entry.map(|_| {
+                Measured::unmeasured(RealResult::Failed {
+                    chain: vec!["Failed to load the input of the day".to_string(), error.clone()],
+                })
+            })
+        };
 
-        (text, result)
+        PuzzleResult {
+            example_a_results: Vec::new(),
+            a_result: failed(a_entry),
+            example_b_results: Vec::new(),
+            b_result: failed(b_entry),
+            meta: DayMeta::default(),
+        }
     }
 
-    fn parse_text_to_given_solution<T: PuzzleOutput, U: PuzzleOutput>(s: String) -> (T, U) {
-        // Unify the line endings
-        let s = s.replace("\r\n", "\n");
-        let mut solutions = s.split("\n\n");
+    /// Tells if any of the executed examples or puzzles did not return the expected result, returned a result
+    /// which is known to be wrong, returned an error, panicked or timed out.
+    pub fn has_failures(&self) -> bool {
+        let example_fails = self
+            .example_a_results
+            .iter()
+            .chain(&self.example_b_results)
+            .any(|example| {
+                matches!(
+                    example.measured.result,
+                    ExampleResult::Fails { .. }
+                        | ExampleResult::Failed { .. }
+                        | ExampleResult::Panicked { .. }
+                        | ExampleResult::TimedOut { .. }
+                )
+            });
+        let real_fails = [&self.a_result, &self.b_result]
+            .into_iter()
+            .flatten()
+            .any(|res| {
+                matches!(
+                    res.result,
+                    RealResult::Fails { .. }
+                        | RealResult::KnownWrong { .. }
+                        | RealResult::Failed { .. }
+                        | RealResult::Panicked { .. }
+                        | RealResult::TimedOut { .. }
+                )
+            });
 
-        let first = solutions
-            .next()
-            .expect("text should not be empty")
-            .parse::<T>()
-            .expect("The first block should be the first solution");
-
-        let second = solutions
-            .next()
-            .expect("text should contain 2 blocks")
-            .parse::<U>()
-            .expect("The second block should be second solution");
-
-        (first, second)
+        example_fails || real_fails
     }
 }
 
-pub struct PuzzleResult {
-    pub example_a_result: Option<ExampleResult>,
-    pub a_result: Option<RealResult>,
-    pub example_b_result: Option<ExampleResult>,
-    pub b_result: Option<RealResult>,
+/// The result of one of the examples of a part.
+pub struct ExampleRun {
+    /// The name of the example file, like ea, ea2 or examples/a/small.txt
+    pub name: String,
+    pub measured: Measured<ExampleResult>,
 }
 
 pub enum ExampleResult {
     /// The example does not exist in the input.
     DoesNotExist,
-    /// The example output has the expected result.
-    Works,
+    /// The example output has the expected result. Also returns the result.
+    Works(String),
     /// The example output has not the expeted result.
     Fails { expected: String, was: String },
+    /// The solver returned an error. Contains the messages of the error and all of its sources.
+    Failed { chain: Vec<String> },
+    /// The solver panicked. Contains the panic message and its location.
+    Panicked { message: String },
+    /// The solver did not finish within the timeout and was abandoned.
+    TimedOut { after: Duration },
+    /// The solver returned [NotImplemented].
+    NotImplemented,
 }
 
 pub enum RealResult {
@@ -241,8 +667,45 @@ pub enum RealResult {
     DoesNotExist,
     /// The puzzle has the given output.
     Output(String),
+    /// The puzzle has an output which is known to be wrong from the submitted answers.
+    /// Contains the reason, like an answer which was already rejected.
+    KnownWrong { was: String, reason: String },
     /// The puzzle has the expected output. Also returns the result.
     Works(String),
     /// The puzzle has not the expected output.
     Fails { expected: String, was: String },
+    /// The solver returned an error. Contains the messages of the error and all of its sources.
+    Failed { chain: Vec<String> },
+    /// The solver panicked. Contains the panic message and its location.
+    Panicked { message: String },
+    /// The solver did not finish within the timeout and was abandoned.
+    TimedOut { after: Duration },
+    /// The solver returned [NotImplemented].
+    NotImplemented,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{run, run_with_params, solvers, Part, RunError};
+
+    #[test]
+    fn solvers_can_be_run_on_any_input() {
+        let input = "svr: aaa\naaa: fft\nfft: bbb\nbbb: dac\ndac: ccc\nccc: out\nyou: aaa out\n";
+
+        assert_eq!(run(2025, 11, Part::A, input), Ok("2".to_string()));
+        assert_eq!(run_with_params(2025, 11, Part::B, input, &"unused=1".parse().unwrap()), Ok("1".to_string()));
+        assert_eq!(run(2015, 1, Part::A, ""), Err(RunError::NoSolver { year: 2015, day: 1, part: Part::A }));
+        assert!(matches!(run(2025, 11, Part::A, "no edges"), Err(RunError::Panicked { .. })));
+    }
+
+    #[test]
+    fn solvers_describe_their_answers() {
+        let solvers = solvers();
+        let info = |year, day, part| solvers.iter().find(|info| (info.year, info.day, info.part) == (year, day, part));
+
+        assert_eq!(info(2024, 2, Part::A).unwrap().output, "Result<usize, SolveError>");
+        assert_eq!(info(2024, 25, Part::B).unwrap().output, "NotImplemented");
+        assert!(info(2025, 11, Part::B).unwrap().parses_separately);
+        assert!(!info(2024, 2, Part::A).unwrap().parses_separately);
+    }
 }
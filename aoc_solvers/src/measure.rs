@@ -0,0 +1,155 @@
+use crate::allocations::{track, AllocationStats};
+use std::time::{Duration, Instant};
+
+/// The result of an example or puzzle, together with measurements of the solver run(s) which created it.
+pub struct Measured<R> {
+    pub result: R,
+    /// How long the (first) solver run took. None if the solver was not executed, like if the input does not exist.
+    pub duration: Option<Duration>,
+    /// How long parsing the input took, if the solver parses it separately, like a [Solver](crate::Solver).
+    /// It is not part of the duration.
+    pub parse_duration: Option<Duration>,
+    /// Statistics over all solver runs, if the solver was executed repeatedly.
+    pub bench: Option<BenchStats>,
+    /// The memory the (first) solver run allocated, if allocations are tracked.
+    pub allocations: Option<AllocationStats>,
+    /// The memory parsing the input allocated, if the solver parses it separately and allocations are tracked.
+    /// It is not part of the allocations.
+    pub parse_allocations: Option<AllocationStats>,
+}
+
+impl<R> Measured<R> {
+    /// Create a measurement for a result which was created without executing the solver.
+    pub(crate) fn unmeasured(result: R) -> Self {
+        Measured {
+            result,
+            duration: None,
+            parse_duration: None,
+            bench: None,
+            allocations: None,
+            parse_allocations: None,
+        }
+    }
+
+    /// Create a measurement for a result of a solver which was abandoned after the given duration.
+    pub(crate) fn timed_out(
+        result: R,
+        after: Duration,
+    ) -> Self {
+        Measured {
+            result,
+            duration: Some(after),
+            parse_duration: None,
+            bench: None,
+            allocations: None,
+            parse_allocations: None,
+        }
+    }
+}
+
+/// Statistics over the durations of repeated solver runs.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchStats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl BenchStats {
+    /// Create the statistics from the given durations. Returns None if no durations are given.
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Some(BenchStats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// The output of the first run of a solver and its measurements.
+pub(crate) struct Run<T> {
+    pub output: T,
+    pub duration: Duration,
+    /// Statistics over all runs, if there was more than one
+    pub bench: Option<BenchStats>,
+    /// The memory the first run allocated, if allocations are tracked
+    pub allocations: Option<AllocationStats>,
+}
+
+/// Execute the given solver the given amount of times (at least once) and measure how long it took.
+/// Returns the output of the first run, its duration and allocations and the statistics over all runs.
+pub(crate) fn measure<T>(
+    repetitions: usize,
+    solver: impl Fn() -> T,
+) -> Run<T> {
+    let start = Instant::now();
+    let (output, allocations) = track(&solver);
+    let duration = start.elapsed();
+
+    if repetitions <= 1 {
+        return Run {
+            output,
+            duration,
+            bench: None,
+            allocations,
+        };
+    }
+
+    let mut durations = vec![duration];
+
+    for _ in 1..repetitions {
+        let start = Instant::now();
+        let _ = solver();
+        durations.push(start.elapsed());
+    }
+
+    Run {
+        output,
+        duration,
+        bench: BenchStats::from_durations(&durations),
+        allocations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::measure::BenchStats;
+    use std::time::Duration;
+
+    #[test]
+    fn bench_stats_work() {
+        let durations = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = BenchStats::from_durations(&durations).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        assert_eq!(stats.std_dev.as_micros(), 1118);
+        assert!(BenchStats::from_durations(&[]).is_none());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::params::Params;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use toml::Value;
+
+/// Information about a day from the file meta.toml in its input directory. Every entry is optional:
+///
+/// ```toml
+/// title = "Restroom Redoubt"
+/// tags = ["simulation", "visualization"]
+/// notes = "Part B searches for the picture of a christmas tree"
+///
+/// [answers]
+/// a = 230436441
+/// b = 8270
+///
+/// [params]
+/// width = 101
+/// height = 103
+///
+/// [examples.ea]
+/// width = 11
+/// height = 7
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayMeta {
+    pub title: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    /// The solutions of the parts
+    pub(crate) answers: Answers,
+    /// The parameters of the puzzle
+    pub(crate) params: BTreeMap<String, Value>,
+    /// The parameters of the examples by their name, like ea2
+    pub(crate) examples: BTreeMap<String, BTreeMap<String, Value>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Answers {
+    a: Option<Value>,
+    b: Option<Value>,
+}
+
+impl DayMeta {
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// The solutions of part A and B, if they are set.
+    pub(crate) fn answers(&self) -> (Option<String>, Option<String>) {
+        (
+            self.answers.a.as_ref().map(value_to_string),
+            self.answers.b.as_ref().map(value_to_string),
+        )
+    }
+
+    /// The parameters of the puzzle.
+    pub(crate) fn puzzle_params(&self) -> Params {
+        Params::from_values(self.params.iter().map(|(key, value)| (key.clone(), value_to_string(value))))
+    }
+
+    /// The parameters of the example with the given name.
+    pub(crate) fn example_params(
+        &self,
+        name: &str,
+    ) -> Params {
+        self.examples
+            .get(name)
+            .map(|params| Params::from_values(params.iter().map(|(key, value)| (key.clone(), value_to_string(value)))))
+            .unwrap_or_default()
+    }
+}
+
+/// Strings are used as they are, so they are not quoted like in TOML.
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::meta::DayMeta;
+
+    #[test]
+    fn every_entry_is_optional() {
+        let meta = DayMeta::parse(
+            "title = \"Code Chronicle\"\ntags = [\"parsing\"]\n\n[answers]\na = 3127\n\n[examples.ea2]\nsteps = 6\n",
+        )
+        .unwrap();
+
+        assert_eq!(meta.title.as_deref(), Some("Code Chronicle"));
+        assert_eq!(meta.tags, vec!["parsing".to_string()]);
+        assert_eq!(meta.answers(), (Some("3127".to_string()), None));
+        assert_eq!(meta.example_params("ea2").get::<usize>("steps"), Some(6));
+        assert_eq!(meta.example_params("ea").get::<usize>("steps"), None);
+
+        assert!(DayMeta::parse("").is_ok());
+        assert!(DayMeta::parse("solution = 3").is_err());
+    }
+}
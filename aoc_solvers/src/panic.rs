@@ -0,0 +1,145 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{catch_unwind, AssertUnwindSafe, PanicHookInfo};
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::SeqCst;
+use std::sync::{Mutex, MutexGuard, Once};
+
+thread_local! {
+    /// Tells if panics on the current thread are currently caught by [isolate].
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    /// The message and location of the last panic caught on the current thread.
+    static CAUGHT_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// How many functions are currently executed by [isolate], on any thread.
+static ISOLATED_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// The panics of rayon workers while functions were isolated, as their message and their message with the
+/// location. A worker panic is passed on to the isolated thread without its location, so it is looked up here
+/// by its message.
+static OTHER_PANICS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Execute the given function and catch any panic it causes, so a single failing
+/// solver does not abort the whole process.
+///
+/// Returns the panic message together with its location if the function panicked.
+/// The message of a caught panic is not printed to stderr, neither are panics of rayon workers
+/// while the function runs, as rayon passes them on to the function.
+pub(crate) fn isolate<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+
+    ISOLATED_RUNS.fetch_add(1, SeqCst);
+    let was_isolated = ISOLATED.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(f));
+    ISOLATED.set(was_isolated);
+
+    let result = result.map_err(|payload| {
+        let message = payload_message(payload.as_ref());
+
+        CAUGHT_PANIC
+            .take()
+            .or_else(|| take_other_panic(&message))
+            // The hook did not record the panic, so at least use the message of the payload
+            .unwrap_or(message)
+    });
+
+    // Panics of workers which were not passed on are not needed anymore once nothing is isolated
+    if ISOLATED_RUNS.fetch_sub(1, SeqCst) == 1 {
+        other_panics().clear();
+    }
+
+    result
+}
+
+/// Install a panic hook which records panics of isolated functions and of rayon workers while functions
+/// are isolated and passes all other panics to the previous hook. The worker threads of [with_timeout]
+/// need no special handling, as they run [isolate] themselves.
+///
+/// [with_timeout]: crate::timeout::with_timeout
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous_hook = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            if ISOLATED.get() {
+                CAUGHT_PANIC.set(Some(panic_message(info)));
+            } else if ISOLATED_RUNS.load(SeqCst) > 0 && rayon::current_thread_index().is_some() {
+                other_panics().push((payload_message(info.payload()), panic_message(info)));
+            } else {
+                previous_hook(info)
+            }
+        }));
+    });
+}
+
+/// Take the recorded panic of a rayon worker with the given message.
+fn take_other_panic(message: &str) -> Option<String> {
+    let mut other_panics = other_panics();
+    let index = other_panics.iter().rposition(|(other, _)| other == message)?;
+
+    Some(other_panics.remove(index).1)
+}
+
+fn other_panics() -> MutexGuard<'static, Vec<(String, String)>> {
+    // A panic while the lock was held does not leave the list in an invalid state
+    OTHER_PANICS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    let message = payload_message(info.payload());
+
+    match info.location() {
+        Some(location) => format!("{message} (at {location})"),
+        None => message,
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::panic::{isolate, take_other_panic};
+    use std::thread;
+
+    #[test]
+    fn panics_are_caught() {
+        assert_eq!(isolate(|| 42), Ok(42));
+
+        let message = isolate(|| -> usize { panic!("oh no") }).unwrap_err();
+        assert!(message.starts_with("oh no (at "), "{message}");
+        assert!(message.contains("panic.rs"), "{message}");
+
+        let message = isolate(|| "x".parse::<usize>().unwrap()).unwrap_err();
+        assert!(message.contains("InvalidDigit"), "{message}");
+    }
+
+    #[test]
+    fn panics_of_rayon_threads_are_caught() {
+        // Called outside of the thread pool, rayon runs both closures on its threads
+        let message = isolate(|| rayon::join(|| 1, || -> usize { panic!("oh no in a worker") })).unwrap_err();
+
+        // The location is only known if the hook recorded the panic of the worker
+        assert!(message.starts_with("oh no in a worker (at "), "{message}");
+        assert!(message.contains("panic.rs"), "{message}");
+    }
+
+    #[test]
+    fn panics_of_other_threads_are_passed_on() {
+        // Only rayon workers belong to the isolated run, other threads might be other tests
+        let recorded = isolate(|| {
+            let _ = thread::spawn(|| panic!("not a worker")).join();
+            take_other_panic("not a worker")
+        });
+
+        assert_eq!(recorded, Ok(None));
+    }
+}
@@ -0,0 +1,108 @@
+use std::any::type_name;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Named parameters of an example or puzzle, like the size of a board or a number of steps,
+/// which are given in the puzzle description instead of the input.
+///
+/// Examples set them in a header before the expected result, like `# width=11 height=7`.
+/// The puzzle sets them in the file `params` next to the puzzle input, so the input itself
+/// stays the same as the downloaded file. Solvers get them from their [PuzzleContext](crate::PuzzleContext).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// Parse parameters from pairs like `width=11`, separated by whitespace.
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        text.split_whitespace()
+            .map(|pair| match pair.split_once('=') {
+                Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+                _ => Err(format!("'{pair}' is not a valid parameter, expected key=value")),
+            })
+            .collect::<Result<_, _>>()
+            .map(Params)
+    }
+
+    pub(crate) fn from_values(values: impl IntoIterator<Item = (String, String)>) -> Self {
+        Params(values.into_iter().collect())
+    }
+
+    /// Combine these parameters with the given ones, which take precedence.
+    pub(crate) fn merged(
+        mut self,
+        overrides: Params,
+    ) -> Self {
+        self.0.extend(overrides.0);
+        self
+    }
+
+    /// Split the header lines starting with # from the given text and parse them as parameters.
+    /// Returns the parameters and the remaining text.
+    pub(crate) fn split_header(text: &str) -> Result<(Self, &str), String> {
+        let mut header = String::new();
+        let mut rest = text;
+
+        while let Some(line) = rest.strip_prefix('#') {
+            let (line, remaining) = line.split_once('\n').unwrap_or((line, ""));
+            header.push_str(line);
+            header.push(' ');
+            rest = remaining;
+        }
+
+        Ok((Self::parse(&header)?, rest))
+    }
+
+    /// Get the parameter with the given key, parsed to the requested type.
+    /// Returns None if it does not exist and panics if it cannot be parsed.
+    pub fn get<T: FromStr<Err: Debug>>(
+        &self,
+        key: &str,
+    ) -> Option<T> {
+        self.0.get(key).map(|value| {
+            value
+                .parse()
+                .unwrap_or_else(|e| panic!("parameter {key}={value} is not a valid {}: {e:?}", type_name::<T>()))
+        })
+    }
+
+    /// Get the parameter with the given key, or the default if it does not exist.
+    pub fn get_or<T: FromStr<Err: Debug>>(
+        &self,
+        key: &str,
+        default: T,
+    ) -> T {
+        self.get(key).unwrap_or(default)
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parse parameters from pairs like `width=11 height=7`, to run a solver with [run_with_params](crate::run_with_params).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Params::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::Params;
+
+    #[test]
+    fn header_is_split_and_parsed() {
+        let (params, rest) = Params::split_header("# width=11 height=7\n#steps=6\n12\n\n#.#\n").unwrap();
+
+        assert_eq!(params.get::<usize>("width"), Some(11));
+        assert_eq!(params.get::<isize>("height"), Some(7));
+        assert_eq!(params.get::<u8>("steps"), Some(6));
+        assert_eq!(params.get_or("threshold", 100), 100);
+        assert_eq!(rest, "12\n\n#.#\n");
+
+        let (params, rest) = Params::split_header("12\n\n#.#").unwrap();
+        assert_eq!(params, Params::default());
+        assert_eq!(rest, "12\n\n#.#");
+
+        assert!(Params::split_header("# width 11\n12").is_err());
+    }
+}
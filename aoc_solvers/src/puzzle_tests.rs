@@ -0,0 +1,111 @@
+//! The tests of every example and puzzle in the input directory of the workspace. They are generated by the
+//! build script, so `cargo test -p aoc_solvers y2024::d20` checks every example and puzzle of 2024 day 20.
+
+use crate::input::Input;
+use crate::registry::{find, Part};
+use crate::{solve_puzzle, ExampleResult, PuzzleResult, RealResult, RunConfig};
+use std::time::Duration;
+
+// The modules y<year>::d<day> with the tests of every day
+include!(concat!(env!("OUT_DIR"), "/puzzle_tests.rs"));
+
+/// The timeout of the tests, like the default of the command line. With a timeout the solvers run on a
+/// worker thread with a large stack instead of the small stack of the test thread.
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Run the solver of the given part with the example of the given name and assert that it works.
+/// Examples of parts which are not implemented yet pass.
+#[allow(dead_code)] // There are no generated tests without an input directory
+pub(crate) fn check_example(
+    year: u16,
+    day: u8,
+    part: Part,
+    name: &str,
+) {
+    let run_config = RunConfig {
+        run_example_a: part == Part::A,
+        run_a: false,
+        run_example_b: part == Part::B,
+        run_b: false,
+        timeout: Some(TIMEOUT),
+        ..RunConfig::default()
+    };
+    let mut input = Input::load(&run_config.input_root, day, year).unwrap_or_else(|e| panic!("{e}"));
+    input.examples_a.retain(|example| example.name == name);
+    input.examples_b.retain(|example| example.name == name);
+
+    let Some(result) = run(year, day, part, run_config, input) else {
+        return;
+    };
+    let examples = match part {
+        Part::A => result.example_a_results,
+        Part::B => result.example_b_results,
+    };
+
+    for example in examples {
+        match example.measured.result {
+            ExampleResult::Works(_) | ExampleResult::DoesNotExist | ExampleResult::NotImplemented => {}
+            ExampleResult::Fails { expected, was } => panic!("expected {expected}, but was {was}"),
+            ExampleResult::Failed { chain } => panic!("returned an error: {}", chain.join(": ")),
+            ExampleResult::Panicked { message } => panic!("panicked: {message}"),
+            ExampleResult::TimedOut { after } => panic!("timed out after {after:?}"),
+        }
+    }
+}
+
+/// Run the solver of the given part with the puzzle input and assert that it returns the solution, if the
+/// solution is known. Without a solution only a failing solver fails the test. A missing solver fails as well.
+#[allow(dead_code)] // There are no generated tests without an input directory
+pub(crate) fn check_puzzle(
+    year: u16,
+    day: u8,
+    part: Part,
+) {
+    let run_config = RunConfig {
+        run_example_a: false,
+        run_a: part == Part::A,
+        run_example_b: false,
+        run_b: part == Part::B,
+        timeout: Some(TIMEOUT),
+        ..RunConfig::default()
+    };
+    let input = Input::load(&run_config.input_root, day, year).unwrap_or_else(|e| panic!("{e}"));
+    let solution_known = input.expected(part).is_some();
+
+    let Some(result) = run(year, day, part, run_config, input) else {
+        panic!("no solver is registered");
+    };
+    let measured = match part {
+        Part::A => result.a_result,
+        Part::B => result.b_result,
+    };
+
+    match measured.expect("the puzzle should be executed").result {
+        // Without a solution any output is accepted
+        RealResult::Works(_) | RealResult::Output(_) => {}
+        RealResult::NotImplemented if !solution_known => {}
+        RealResult::DoesNotExist => panic!("the puzzle input does not exist"),
+        RealResult::NotImplemented => panic!("the solution is known, but the solver is not implemented"),
+        RealResult::Fails { expected, was } => panic!("expected {expected}, but was {was}"),
+        RealResult::KnownWrong { was, reason } => panic!("{was} is known to be wrong: {reason}"),
+        RealResult::Failed { chain } => panic!("returned an error: {}", chain.join(": ")),
+        RealResult::Panicked { message } => panic!("panicked: {message}"),
+        RealResult::TimedOut { after } => panic!("timed out after {after:?}"),
+    }
+}
+
+/// Run the given part with the given input. Returns None if the part has no solver.
+fn run(
+    year: u16,
+    day: u8,
+    part: Part,
+    run_config: RunConfig,
+    input: Input,
+) -> Option<PuzzleResult> {
+    let entry = find(year, day, part)?;
+
+    Some(match part {
+        Part::A => solve_puzzle(run_config, input, Some(entry), None),
+        Part::B => solve_puzzle(run_config, input, None, Some(entry)),
+    })
+}
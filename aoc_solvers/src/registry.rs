@@ -0,0 +1,210 @@
+use crate::{Answer, Failure, PuzzleContext};
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A solver which parses the puzzle input once and solves both parts with the parsed input. Parsing is
+/// measured separately, so the durations of the parts do not contain it. The implementation is registered
+/// with the [solver](proc_macros::solver) attribute on the impl block, like `#[solver(year = 2024, day = 20)]`.
+pub trait Solver {
+    /// The parsed puzzle input, which is shared by both parts
+    type Parsed: Send + Sync + 'static;
+    /// The answer of part A
+    type A: Display + Send + 'static;
+    /// The answer of part B
+    type B: Display + Send + 'static;
+
+    fn parse(input: &str) -> Self::Parsed;
+
+    fn part_a(
+        parsed: &Self::Parsed,
+        context: &PuzzleContext,
+    ) -> Self::A;
+
+    fn part_b(
+        parsed: &Self::Parsed,
+        context: &PuzzleContext,
+    ) -> Self::B;
+}
+
+/// A type erased solver function, which takes the puzzle input and its context and returns the answer.
+pub(crate) type SolverFn = fn(&str, &PuzzleContext) -> Box<dyn Answer>;
+
+/// A type erased parsed puzzle input of a [Solver], which is shared by the runs of both parts.
+pub(crate) type ParsedInput = Arc<dyn Any + Send + Sync>;
+
+/// How a registered solver is called.
+#[derive(Clone, Copy)]
+pub(crate) enum SolverKind {
+    /// A function which takes the puzzle input
+    Function(SolverFn),
+    /// A part of a [Solver], which takes the input created by the parser
+    Parsed {
+        parser: fn(&str) -> ParsedInput,
+        solver: fn(&ParsedInput, &PuzzleContext) -> Box<dyn Answer>,
+    },
+}
+
+/// Parse the given input with the given [Solver].
+pub(crate) fn parse<S: Solver>(input: &str) -> ParsedInput {
+    Arc::new(S::parse(input))
+}
+
+/// Solve part A of the given [Solver] with the input created by [parse].
+pub(crate) fn part_a<S: Solver>(
+    parsed: &ParsedInput,
+    context: &PuzzleContext,
+) -> Box<dyn Answer> {
+    Box::new(S::part_a(downcast::<S>(parsed), context))
+}
+
+/// Solve part B of the given [Solver] with the input created by [parse].
+pub(crate) fn part_b<S: Solver>(
+    parsed: &ParsedInput,
+    context: &PuzzleContext,
+) -> Box<dyn Answer> {
+    Box::new(S::part_b(downcast::<S>(parsed), context))
+}
+
+fn downcast<S: Solver>(parsed: &ParsedInput) -> &S::Parsed {
+    parsed
+        .downcast_ref()
+        .expect("the input should be parsed by the parser of the same solver")
+}
+
+/// Box the answer of a solver which returns a Result. An error is boxed as a [Failure], so the runner reports it.
+pub(crate) fn fallible<T: Display + Send + 'static, E: Error + 'static>(result: Result<T, E>) -> Box<dyn Answer> {
+    match result {
+        Ok(answer) => Box::new(answer),
+        Err(error) => Box::new(Failure::new(&error)),
+    }
+}
+
+/// The part of a puzzle.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    A,
+    B,
+}
+
+/// A solver for one part of a puzzle. Every function with the [solver](proc_macros::solver) attribute
+/// is registered as an entry, and every implementation of [Solver] as one entry per part.
+pub(crate) struct SolverEntry {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub solver: SolverKind,
+    /// The type of the answer as it is written in the solver, like usize or Result<usize, SolveError>
+    pub output: &'static str,
+    /// Overrides the default timeout of the run configuration for this solver.
+    pub timeout: Option<Duration>,
+}
+
+impl SolverEntry {
+    fn info(&self) -> SolverInfo {
+        SolverInfo {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            output: self.output,
+            parses_separately: matches!(self.solver, SolverKind::Parsed { .. }),
+            timeout: self.timeout,
+        }
+    }
+}
+
+/// Information about a registered solver for one part of a puzzle.
+#[derive(Clone, Debug)]
+pub struct SolverInfo {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The type of the answer as it is written in the solver, like usize or Result<usize, SolveError>
+    pub output: &'static str,
+    /// Tells if the solver is a part of a [Solver], which parses the input separately
+    pub parses_separately: bool,
+    /// The timeout of the solver, if it overrides the default timeout
+    pub timeout: Option<Duration>,
+}
+
+inventory::collect!(SolverEntry);
+
+/// Find the solver for the given year, day and part, if it exists.
+pub(crate) fn find(
+    year: u16,
+    day: u8,
+    part: Part,
+) -> Option<&'static SolverEntry> {
+    entries().find(|e| e.year == year && e.day == day && e.part == part)
+}
+
+/// Every registered solver.
+pub(crate) fn entries() -> impl Iterator<Item = &'static SolverEntry> {
+    inventory::iter::<SolverEntry>.into_iter()
+}
+
+/// Every registered solver, ordered by year, day and part.
+pub fn solvers() -> Vec<SolverInfo> {
+    let mut solvers = entries().map(SolverEntry::info).collect::<Vec<_>>();
+    solvers.sort_by_key(|info| (info.year, info.day, info.part));
+    solvers
+}
+
+/// Every year and day at least one solver is registered for, in ascending order.
+pub fn implemented_days() -> BTreeSet<(u16, u8)> {
+    entries().map(|e| (e.year, e.day)).collect()
+}
+
+/// Every year at least one solver is registered for, in ascending order.
+pub fn implemented_years() -> BTreeSet<u16> {
+    entries().map(|e| e.year).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::Params;
+    use crate::registry::{parse, part_a, part_b, Part, Solver};
+    use crate::PuzzleContext;
+
+    struct Sum;
+
+    impl Solver for Sum {
+        type Parsed = Vec<u32>;
+        type A = u32;
+        type B = String;
+
+        fn parse(input: &str) -> Vec<u32> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_a(
+            numbers: &Vec<u32>,
+            _context: &PuzzleContext,
+        ) -> u32 {
+            numbers.iter().sum()
+        }
+
+        fn part_b(
+            numbers: &Vec<u32>,
+            _context: &PuzzleContext,
+        ) -> String {
+            format!("{numbers:?}")
+        }
+    }
+
+    #[test]
+    fn both_parts_share_the_parsed_input() {
+        let parsed = parse::<Sum>("1\n2\n3");
+        let context = PuzzleContext {
+            part: Part::A,
+            example: None,
+            params: Params::default(),
+        };
+
+        assert_eq!(part_a::<Sum>(&parsed, &context).to_string(), "6");
+        assert_eq!(part_b::<Sum>(&parsed, &context).to_string(), "[1, 2, 3]");
+    }
+}
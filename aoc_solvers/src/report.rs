@@ -0,0 +1,406 @@
+use crate::{AllocationStats, ExampleResult, Measured, PuzzleResult, RealResult};
+use serde::Serialize;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// A machine-readable report over the results of one or more puzzles, which can be
+/// written as JSON or JUnit XML.
+#[derive(Default, Serialize)]
+pub struct Report {
+    pub entries: Vec<ReportEntry>,
+}
+
+/// The result of a single executed example or puzzle.
+#[derive(Serialize)]
+pub struct ReportEntry {
+    pub year: u16,
+    pub day: u8,
+    pub part: ReportPart,
+    /// The name of the example file, like ea2, if this is the result of an example
+    pub example: Option<String>,
+    pub status: ReportStatus,
+    /// The expected result, if known
+    pub expected: Option<String>,
+    /// The result the solver returned, if it was executed
+    pub actual: Option<String>,
+    /// Why the actual result is known to be wrong, like that it was already rejected
+    pub reason: Option<String>,
+    /// How long the solver took in seconds, if it was executed
+    pub duration_secs: Option<f64>,
+    /// How long parsing the input took in seconds, if the solver parses it separately
+    pub parse_duration_secs: Option<f64>,
+    /// The memory the solver allocated, if allocations are tracked
+    pub allocations: Option<AllocationStats>,
+    /// The memory parsing the input allocated, if the solver parses it separately and allocations are tracked
+    pub parse_allocations: Option<AllocationStats>,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportPart {
+    ExampleA,
+    A,
+    ExampleB,
+    B,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    /// The solver returned the expected result.
+    Works,
+    /// The solver did not return the expected result.
+    Fails,
+    /// The solver returned a result, but there is no expected result to compare it with.
+    Output,
+    /// The solver returned a result which is known to be wrong from the submitted answers.
+    KnownWrong,
+    /// The solver returned an error. The actual result contains the messages of the error and its sources.
+    Failed,
+    /// The solver panicked. The actual result contains the panic message.
+    Panicked,
+    /// The solver did not finish within the timeout. The duration is the timeout.
+    TimedOut,
+    /// The input does not exist, so the solver was not executed.
+    DoesNotExist,
+    /// The solver does not solve its part yet.
+    NotImplemented,
+}
+
+impl Report {
+    /// Add an entry for every executed example and puzzle of the given result.
+    pub fn add(
+        &mut self,
+        year: u16,
+        day: u8,
+        result: &PuzzleResult,
+    ) {
+        let examples = result
+            .example_a_results
+            .iter()
+            .map(|example| (ReportPart::ExampleA, example))
+            .chain(result.example_b_results.iter().map(|example| (ReportPart::ExampleB, example)));
+        let reals = [
+            (ReportPart::A, &result.a_result),
+            (ReportPart::B, &result.b_result),
+        ];
+
+        for (part, example) in examples {
+            let outcome = match &example.measured.result {
+                ExampleResult::DoesNotExist => (ReportStatus::DoesNotExist, None, None),
+                ExampleResult::Works(output) => (
+                    ReportStatus::Works,
+                    Some(output.clone()),
+                    Some(output.clone()),
+                ),
+                ExampleResult::Fails { expected, was } => (
+                    ReportStatus::Fails,
+                    Some(expected.clone()),
+                    Some(was.clone()),
+                ),
+                ExampleResult::Failed { chain } => (ReportStatus::Failed, None, Some(chain.join(": "))),
+                ExampleResult::Panicked { message } => {
+                    (ReportStatus::Panicked, None, Some(message.clone()))
+                }
+                ExampleResult::TimedOut { .. } => (ReportStatus::TimedOut, None, None),
+                ExampleResult::NotImplemented => (ReportStatus::NotImplemented, None, None),
+            };
+            self.push(year, day, (part, Some(example.name.clone())), outcome, None, &example.measured);
+        }
+
+        for (part, measured) in reals {
+            if let Some(measured) = measured {
+                let outcome = match &measured.result {
+                    RealResult::DoesNotExist => (ReportStatus::DoesNotExist, None, None),
+                    RealResult::Output(output) => (ReportStatus::Output, None, Some(output.clone())),
+                    RealResult::KnownWrong { was, .. } => (ReportStatus::KnownWrong, None, Some(was.clone())),
+                    RealResult::Works(output) => (
+                        ReportStatus::Works,
+                        Some(output.clone()),
+                        Some(output.clone()),
+                    ),
+                    RealResult::Fails { expected, was } => (
+                        ReportStatus::Fails,
+                        Some(expected.clone()),
+                        Some(was.clone()),
+                    ),
+                    RealResult::Failed { chain } => (ReportStatus::Failed, None, Some(chain.join(": "))),
+                    RealResult::Panicked { message } => {
+                        (ReportStatus::Panicked, None, Some(message.clone()))
+                    }
+                    RealResult::TimedOut { .. } => (ReportStatus::TimedOut, None, None),
+                    RealResult::NotImplemented => (ReportStatus::NotImplemented, None, None),
+                };
+                let reason = match &measured.result {
+                    RealResult::KnownWrong { reason, .. } => Some(reason.clone()),
+                    _ => None,
+                };
+                self.push(year, day, (part, None), outcome, reason, measured);
+            }
+        }
+    }
+
+    /// Add an entry. The part contains the name of the example, if it is one.
+    /// The outcome consists of the status, the expected and the actual result. The reason tells why the
+    /// actual result is known to be wrong.
+    fn push<R>(
+        &mut self,
+        year: u16,
+        day: u8,
+        (part, example): (ReportPart, Option<String>),
+        (status, expected, actual): (ReportStatus, Option<String>, Option<String>),
+        reason: Option<String>,
+        measured: &Measured<R>,
+    ) {
+        self.entries.push(ReportEntry {
+            year,
+            day,
+            part,
+            example,
+            status,
+            expected,
+            actual,
+            reason,
+            duration_secs: measured.duration.as_ref().map(Duration::as_secs_f64),
+            parse_duration_secs: measured.parse_duration.as_ref().map(Duration::as_secs_f64),
+            allocations: measured.allocations,
+            parse_allocations: measured.parse_allocations,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("the report should always be serializable")
+    }
+
+    /// Create a JUnit XML document with one test suite per day and one test case per example or puzzle.
+    /// Missing inputs and parts which are not implemented are reported as skipped, outputs without an
+    /// expected result as passed, outputs which are known to be wrong as failures and returned errors,
+    /// panics and timeouts as errors.
+    pub fn to_junit_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let count = |status: ReportStatus| self.entries.iter().filter(|e| e.status == status).count();
+
+        writeln!(
+            xml,
+            "<testsuites name=\"advent_of_code\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            self.entries.len(),
+            count(ReportStatus::Fails) + count(ReportStatus::KnownWrong),
+            count(ReportStatus::Failed) + count(ReportStatus::Panicked) + count(ReportStatus::TimedOut),
+            count(ReportStatus::DoesNotExist) + count(ReportStatus::NotImplemented),
+        )
+        .unwrap();
+
+        for day_entries in self.entries.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+            let (year, day) = (day_entries[0].year, day_entries[0].day);
+            let count = |status: ReportStatus| day_entries.iter().filter(|e| e.status == status).count();
+            let time = day_entries.iter().filter_map(|e| e.duration_secs).sum::<f64>();
+
+            writeln!(
+                xml,
+                "  <testsuite name=\"{year} day {day}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{time:.6}\">",
+                day_entries.len(),
+                count(ReportStatus::Fails) + count(ReportStatus::KnownWrong),
+                count(ReportStatus::Failed) + count(ReportStatus::Panicked) + count(ReportStatus::TimedOut),
+                count(ReportStatus::DoesNotExist) + count(ReportStatus::NotImplemented),
+            )
+            .unwrap();
+
+            for entry in day_entries {
+                let part = match entry.part {
+                    ReportPart::ExampleA => "example a",
+                    ReportPart::A => "puzzle a",
+                    ReportPart::ExampleB => "example b",
+                    ReportPart::B => "puzzle b",
+                };
+                let name = match &entry.example {
+                    Some(example) => escape_xml(&format!("{part} ({example})")),
+                    None => part.to_string(),
+                };
+                let time = entry.duration_secs.unwrap_or_default();
+
+                write!(
+                    xml,
+                    "    <testcase name=\"{name}\" classname=\"y{year}.d{day}\" time=\"{time:.6}\""
+                )
+                .unwrap();
+
+                match entry.status {
+                    ReportStatus::Works => xml.push_str("/>\n"),
+                    ReportStatus::Fails => {
+                        let message = format!(
+                            "Expected {}, but result was {}",
+                            entry.expected.as_deref().unwrap_or_default(),
+                            entry.actual.as_deref().unwrap_or_default()
+                        );
+                        writeln!(
+                            xml,
+                            ">\n      <failure message=\"{}\"/>\n    </testcase>",
+                            escape_xml(&message)
+                        )
+                        .unwrap();
+                    }
+                    ReportStatus::KnownWrong => {
+                        let message = format!(
+                            "Result {} is known to be wrong, {}",
+                            entry.actual.as_deref().unwrap_or_default(),
+                            entry.reason.as_deref().unwrap_or_default()
+                        );
+                        writeln!(
+                            xml,
+                            ">\n      <failure message=\"{}\"/>\n    </testcase>",
+                            escape_xml(&message)
+                        )
+                        .unwrap();
+                    }
+                    ReportStatus::Failed | ReportStatus::Panicked => writeln!(
+                        xml,
+                        ">\n      <error message=\"{}\"/>\n    </testcase>",
+                        escape_xml(entry.actual.as_deref().unwrap_or_default())
+                    )
+                    .unwrap(),
+                    ReportStatus::TimedOut => writeln!(
+                        xml,
+                        ">\n      <error message=\"timed out after {time}s\"/>\n    </testcase>"
+                    )
+                    .unwrap(),
+                    ReportStatus::Output => writeln!(
+                        xml,
+                        ">\n      <system-out>{}</system-out>\n    </testcase>",
+                        escape_xml(entry.actual.as_deref().unwrap_or_default())
+                    )
+                    .unwrap(),
+                    ReportStatus::DoesNotExist => {
+                        xml.push_str(">\n      <skipped message=\"input does not exist\"/>\n    </testcase>\n")
+                    }
+                    ReportStatus::NotImplemented => {
+                        xml.push_str(">\n      <skipped message=\"not implemented\"/>\n    </testcase>\n")
+                    }
+                }
+            }
+
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::Report;
+    use crate::{DayMeta, ExampleResult, ExampleRun, Measured, PuzzleResult, RealResult};
+    use std::time::Duration;
+
+    fn measured<R>(result: R) -> Option<Measured<R>> {
+        Some(Measured {
+            result,
+            duration: Some(Duration::from_millis(5)),
+            parse_duration: None,
+            bench: None,
+            allocations: None,
+            parse_allocations: None,
+        })
+    }
+
+    fn report() -> Report {
+        let mut report = Report::default();
+        report.add(
+            2024,
+            5,
+            &PuzzleResult {
+                example_a_results: vec![ExampleRun {
+                    name: "ea".to_string(),
+                    measured: measured(ExampleResult::Works("1".to_string())).unwrap(),
+                }],
+                a_result: measured(RealResult::Fails {
+                    expected: "42".to_string(),
+                    was: "<43>".to_string(),
+                }),
+                example_b_results: vec![],
+                b_result: Some(Measured::unmeasured(RealResult::DoesNotExist)),
+                meta: DayMeta::default(),
+            },
+        );
+        report
+    }
+
+    #[test]
+    fn json_report_works() {
+        let json = report().to_json();
+
+        assert!(json.contains(r#""part": "example_a""#));
+        assert!(json.contains(r#""example": "ea""#));
+        assert!(json.contains(r#""status": "fails""#));
+        assert!(json.contains(r#""actual": "<43>""#));
+        assert!(json.contains(r#""status": "does_not_exist""#));
+    }
+
+    #[test]
+    fn junit_report_works() {
+        let xml = report().to_junit_xml();
+
+        assert!(xml.contains(r#"<testsuites name="advent_of_code" tests="3" failures="1" errors="0" skipped="1">"#));
+        assert!(xml.contains(r#"<testsuite name="2024 day 5" tests="3" failures="1" errors="0" skipped="1" time="0.010000">"#));
+        assert!(xml.contains(r#"<failure message="Expected 42, but result was &lt;43&gt;"/>"#));
+        assert!(xml.contains(r#"<skipped message="input does not exist"/>"#));
+        assert!(xml.contains(r#"<testcase name="example a (ea)" classname="y2024.d5""#));
+    }
+
+    #[test]
+    fn timeouts_are_errors() {
+        let mut report = Report::default();
+        report.add(
+            2023,
+            20,
+            &PuzzleResult {
+                example_a_results: vec![],
+                a_result: None,
+                example_b_results: vec![],
+                b_result: Some(Measured::timed_out(
+                    RealResult::TimedOut {
+                        after: Duration::from_secs(60),
+                    },
+                    Duration::from_secs(60),
+                )),
+                meta: DayMeta::default(),
+            },
+        );
+
+        assert!(report.to_json().contains(r#""status": "timed_out""#));
+        let xml = report.to_junit_xml();
+        assert!(xml.contains(r#"<testsuites name="advent_of_code" tests="1" failures="0" errors="1" skipped="0">"#));
+        assert!(xml.contains(r#"<error message="timed out after 60s"/>"#));
+    }
+
+    #[test]
+    fn known_wrong_results_have_a_reason() {
+        let mut report = Report::default();
+        report.add(
+            2024,
+            7,
+            &PuzzleResult {
+                example_a_results: vec![],
+                a_result: measured(RealResult::KnownWrong {
+                    was: "42".to_string(),
+                    reason: "42 was already rejected".to_string(),
+                }),
+                example_b_results: vec![],
+                b_result: None,
+                meta: DayMeta::default(),
+            },
+        );
+
+        assert!(report.to_json().contains(r#""reason": "42 was already rejected""#));
+        let xml = report.to_junit_xml();
+        assert!(xml.contains(r#"<failure message="Result 42 is known to be wrong, 42 was already rejected"/>"#));
+    }
+}
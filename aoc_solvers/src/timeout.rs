@@ -0,0 +1,54 @@
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+/// The stack size of the worker threads. Some solvers recurse deeply, so this is more than
+/// the stack of the main thread usually has.
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Execute the given function on a worker thread and wait at most for the given timeout.
+///
+/// Returns None if the function did not finish in time. Threads cannot be stopped from the outside,
+/// so the worker keeps running in the background until it finishes or the process exits.
+///
+/// Panics if the function panics, so panics should be caught inside of it.
+pub(crate) fn with_timeout<T: Send + 'static>(
+    timeout: Duration,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Option<T> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::Builder::new()
+        .name("solver".to_string())
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            // The receiver is gone if the timeout already passed, so nobody is interested in the result anymore
+            let _ = sender.send(f());
+        })
+        .expect("the solver thread should be spawnable");
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => panic!("the solver thread stopped without a result"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timeout::with_timeout;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn slow_functions_are_abandoned() {
+        assert_eq!(with_timeout(Duration::from_secs(10), || 42), Some(42));
+
+        let slow = with_timeout(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_secs(10));
+            42
+        });
+        assert_eq!(slow, None);
+    }
+}
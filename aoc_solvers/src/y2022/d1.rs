@@ -1,6 +1,8 @@
 use itertools::Itertools;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 1, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .split("\n\n")
@@ -9,6 +11,7 @@ pub fn solve_a(input: &str) -> usize {
         .unwrap()
 }
 
+#[solver(year = 2022, day = 1, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .split("\n\n")
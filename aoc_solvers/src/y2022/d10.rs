@@ -1,7 +1,9 @@
+use crate::PuzzleContext;
 use helpers::prelude::*;
-use proc_macros::{from_regex, tile};
+use proc_macros::{from_regex, solver, tile};
 use Instruction::*;
 
+#[solver(year = 2022, day = 10, part = a)]
 pub fn solve_a(input: &str) -> isize {
     let mut instructions = input
         .lines()
@@ -39,7 +41,11 @@ pub fn solve_a(input: &str) -> isize {
     }
 }
 
-pub fn solve_b(input: &str) -> Board<Tile> {
+#[solver(year = 2022, day = 10, part = b)]
+pub fn solve_b(
+    input: &str,
+    context: &PuzzleContext,
+) -> Result<String, SolveError> {
     let mut instructions = input
         .lines()
         .map(Instruction::from_regex)
@@ -54,7 +60,12 @@ pub fn solve_b(input: &str) -> Board<Tile> {
 
     loop {
         if instructions.is_empty() {
-            break board
+            break match ocr_board(&board, |tile| matches!(tile, Tile::Lit)) {
+                Ok(letters) => Ok(letters),
+                // the example does not show letters, so its image is returned as it is
+                Err(_) if context.is_example() => Ok(board.to_string()),
+                Err(e) => Err(SolveError::new(format!("Failed to read the letters of the image: {e}"))),
+            }
         }
 
         if (x..=(x + 2)).contains(&(cycle % 40)) {
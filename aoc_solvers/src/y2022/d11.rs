@@ -4,7 +4,9 @@ use std::{
 };
 
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 11, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut monkeys = input
         .split("\n\n")
@@ -53,6 +55,7 @@ pub fn solve_a(input: &str) -> usize {
 /// for all possible test values the monkeys might have. So an operation of one monkey is treated as if all monkeys
 /// perform it with their respective test values. Using this, each monkey can perform their operation and
 /// afterwards check for their remainder in the [SuperModulo].
+#[solver(year = 2022, day = 11, part = b)]
 pub fn solve_b(input: &str) -> u128 {
     let monkeys = input
         .split("\n\n")
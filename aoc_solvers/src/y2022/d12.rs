@@ -1,6 +1,8 @@
 use pad::board::Board;
 use pathfinding::prelude::astar;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 12, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
     let start = board.get_positions_of(&Tile::Start).next().unwrap();
@@ -29,6 +31,7 @@ pub fn solve_a(input: &str) -> usize {
     res.1
 }
 
+#[solver(year = 2022, day = 12, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
 
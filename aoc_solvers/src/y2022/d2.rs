@@ -1,6 +1,8 @@
 use self::Shape::*;
 use self::Strat::*;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 2, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -14,6 +16,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2022, day = 2, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .lines()
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 3, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -19,6 +21,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2022, day = 3, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let lines: Vec<_> = input.lines().collect();
 
@@ -1,6 +1,8 @@
 use regex::Regex;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 4, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let regex = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
 
@@ -22,6 +24,7 @@ pub fn solve_a(input: &str) -> usize {
         .count()
 }
 
+#[solver(year = 2022, day = 4, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let regex = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
 
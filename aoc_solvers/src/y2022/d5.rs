@@ -1,7 +1,9 @@
 use helpers::prelude::*;
 
 use regex::Regex;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 5, part = a)]
 pub fn solve_a(input: &str) -> String {
     let mut blocks = input.split("\n\n");
     let mut stacks = Stacks::from(blocks.next().unwrap());
@@ -14,6 +16,7 @@ pub fn solve_a(input: &str) -> String {
     stacks.tops()
 }
 
+#[solver(year = 2022, day = 5, part = b)]
 pub fn solve_b(input: &str) -> String {
     let mut blocks = input.split("\n\n");
     let mut stacks = Stacks::from(blocks.next().unwrap());
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 6, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let chars = input.chars().collect::<Vec<_>>();
 
@@ -10,6 +12,7 @@ pub fn solve_a(input: &str) -> usize {
         .unwrap() + 4
 }
 
+#[solver(year = 2022, day = 6, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let chars = input.chars().collect::<Vec<_>>();
 
@@ -1,7 +1,8 @@
-use proc_macros::from_regex;
+use proc_macros::{from_regex, solver};
 use std::collections::HashMap;
 use Line::*;
 
+#[solver(year = 2022, day = 7, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let lines = input
         .lines()
@@ -17,6 +18,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2022, day = 7, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let lines = input
         .lines()
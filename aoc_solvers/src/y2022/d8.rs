@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 8, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let board = Board::from_str_using_mapping(input, |c| c.to_digit(10).unwrap() as usize).unwrap();
 
@@ -34,6 +36,7 @@ fn add_visible<'a>(
     }
 }
 
+#[solver(year = 2022, day = 8, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let board = Board::from_str_using_mapping(input, |c| c.to_digit(10).unwrap() as usize).unwrap();
 
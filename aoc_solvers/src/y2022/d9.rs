@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2022, day = 9, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let input = parse_input(input);
 
@@ -33,6 +35,7 @@ pub fn solve_a(input: &str) -> usize {
     visited.len()
 }
 
+#[solver(year = 2022, day = 9, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let input = parse_input(input);
     let mut knots = [p!(0, 0); 10];
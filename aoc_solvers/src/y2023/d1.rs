@@ -1,3 +1,6 @@
+use proc_macros::solver;
+
+#[solver(year = 2023, day = 1, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -11,6 +14,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2023, day = 1, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .lines()
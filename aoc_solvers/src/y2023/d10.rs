@@ -2,7 +2,9 @@ use std::collections::HashMap;
 use geo::{Contains, Coord, LineString, point, Polygon};
 use helpers::prelude::*;
 use Tile::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 10, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let tile_map = input
         .lines()
@@ -44,6 +46,7 @@ pub fn solve_a(input: &str) -> usize {
     count / 2
 }
 
+#[solver(year = 2023, day = 10, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let tile_map = input
         .lines()
@@ -5,7 +5,9 @@ use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelIterator;
 
 use Tile::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 11, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut space = input
         .lines()
@@ -34,7 +36,7 @@ pub fn solve_a(input: &str) -> usize {
 
     let pairs = galaxy_positions.iter().copied().combinations(2).collect::<Vec<_>>();
 
-    println!("{}", pairs.len());
+    debug!("{} pairs of galaxies", pairs.len());
 
     pairs
         .into_par_iter()
@@ -54,6 +56,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum::<usize>()
 }
 
+#[solver(year = 2023, day = 11, part = b)]
 pub fn solve_b(input: &str) -> u128 {
     let space = input
         .lines()
@@ -2,7 +2,10 @@ use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelIterator;
 
 use Spring::*;
+use crate::NotImplemented;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 12, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let rows = input
         .lines()
@@ -25,8 +28,9 @@ pub fn solve_a(input: &str) -> usize {
 // 4. continue with the current position until a non valid position was reached
 // 5. backtrack to the point before moving the current position, move the previous one
 // 6. Repeat until done
-pub fn solve_b(_input: &str) -> usize {
-    0
+#[solver(year = 2023, day = 12, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {
+    NotImplemented
 }
 
 #[derive(Clone, Debug)]
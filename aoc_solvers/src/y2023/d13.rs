@@ -1,7 +1,9 @@
-use proc_macros::tile;
+use crate::NotImplemented;
+use proc_macros::{solver, tile};
 
 use helpers::prelude::*;
 
+#[solver(year = 2023, day = 13, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let blocks = input
         .split("\n\n")
@@ -14,8 +16,9 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
-pub fn solve_b(_input: &str) -> usize {
-    0
+#[solver(year = 2023, day = 13, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {
+    NotImplemented
 }
 
 fn get_value(tile_map: TileMap<Tile>) -> usize {
@@ -1,7 +1,9 @@
 use pad::p;
 use Tile::*;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 14, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut tile_map = TileMap::<Tile>::from(input);
 
@@ -10,6 +12,7 @@ pub fn solve_a(input: &str) -> usize {
     calculate_north_weight(&tile_map)
 }
 
+#[solver(year = 2023, day = 14, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut tile_map = TileMap::<Tile>::from(input);
     run_n_times_with_cycle(
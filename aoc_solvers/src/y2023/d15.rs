@@ -1,11 +1,14 @@
 use Operation::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 15, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input.split(",")
         .map(calculate_hash)
         .sum()
 }
 
+#[solver(year = 2023, day = 15, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let entries = input
         .split(",")
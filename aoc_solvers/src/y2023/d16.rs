@@ -4,7 +4,9 @@ use rayon::iter::ParallelIterator;
 use rayon::prelude::IntoParallelIterator;
 
 use crate::y2023::d16::Tile::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 16, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let tile_map = TileMap::<Tile>::from(input);
 
@@ -13,6 +15,7 @@ pub fn solve_a(input: &str) -> usize {
     beamer.count_unique_energized_tiles()
 }
 
+#[solver(year = 2023, day = 16, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let tile_map = TileMap::<Tile>::from(input);
 
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 use colored::Colorize;
 use helpers::prelude::*;
 use pathfinding::prelude::astar;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 17, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let tile_map = TileMap::<Tile>::from(input);
 
@@ -25,6 +27,7 @@ pub fn solve_a(input: &str) -> usize {
 
 // TODO this does not work. I added 1 to the returned puzzle result, just for fun, and it was correct.
 //  It works on the example input tho...
+#[solver(year = 2023, day = 17, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let tile_map = TileMap::<Tile>::from(input);
 
@@ -39,7 +42,7 @@ pub fn solve_b(input: &str) -> usize {
     );
 
     if let Some((path, c)) = res {
-        print_path(&tile_map, path);
+        trace_path(&tile_map, path);
         c
     } else {
         0
@@ -118,33 +121,40 @@ fn distance(pos: Position, goal: Position) -> usize {
     (pos.x - goal.x).unsigned_abs() + (pos.y - goal.y).unsigned_abs()
 }
 
-/// For debugging
-#[allow(dead_code)]
-fn print_path(
+/// For debugging, traces the tile map with the path drawn on it
+fn trace_path(
     tile_map: &TileMap<Tile>,
     path: Vec<(Position, Vec<Direction>)>,
 ) {
+    if !helpers::trace::enabled(helpers::trace::Level::Trace) {
+        return;
+    }
+
     let map = path
         .into_iter()
         .collect::<HashMap<_, _>>();
 
     for y in 0..tile_map.height {
+        let mut row = String::new();
+
         for x in 0..tile_map.width {
             let pos = p!(x, y);
 
             if let Some(dirs) = map.get(&pos) {
-                match dirs.last() {
-                    Some(XP) => print!("{}", ">".red()),
-                    Some(XM) => print!("{}", "<".red()),
-                    Some(YP) => print!("{}", "v".red()),
-                    Some(YM) => print!("{}", "^".red()),
-                    _ => print!("{}", "s".red()) // the only tile with no direction is start
-                }
+                let symbol = match dirs.last() {
+                    Some(XP) => ">",
+                    Some(XM) => "<",
+                    Some(YP) => "v",
+                    Some(YM) => "^",
+                    _ => "s" // the only tile with no direction is start
+                };
+                row.push_str(&symbol.red().to_string())
             } else {
-                print!("{}", <Tile as Into<char>>::into(tile_map.get(pos)))
+                row.push(<Tile as Into<char>>::into(tile_map.get(pos)))
             }
         }
-        println!()
+
+        trace!("{row}")
     }
 }
 
@@ -1,7 +1,9 @@
 use geo::{Contains, Coord, LineString, point, Polygon};
 
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 18, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let digs = input.lines().map(Dig::from_a).collect::<Vec<_>>();
 
@@ -44,6 +46,7 @@ pub fn solve_a(input: &str) -> usize {
     dig_positions.len()
 }
 
+#[solver(year = 2023, day = 18, part = b)]
 pub fn solve_b(input: &str) -> u128 {
     let digs = input.lines().map(Dig::from_b).collect::<Vec<_>>();
 
@@ -52,7 +55,7 @@ pub fn solve_b(input: &str) -> u128 {
 
     let length_sum: usize = digs.iter().map(|d| d.length).sum::<usize>();
 
-    println!("{length_sum}");
+    debug!("Length of all digs: {length_sum}");
 
     for dig in digs {
         let length = dig.length as isize;
@@ -82,7 +85,7 @@ pub fn solve_b(input: &str) -> u128 {
         sum += ((pos.y + next_pos.y) * (pos.x - next_pos.x)) as i128
     }
 
-    positions.iter().for_each(|pos| println!("{pos:?}"));
+    positions.iter().for_each(|pos| trace!("{pos:?}"));
 
     (sum / 2).unsigned_abs()
 }
@@ -4,7 +4,10 @@ use crate::y2023::d19::Check::*;
 use crate::y2023::d19::FollowUp::*;
 use crate::y2023::d19::Instruction::*;
 use crate::y2023::d19::Value::*;
+use crate::NotImplemented;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 19, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let split = input.split("\r\n\r\n").collect::<Vec<_>>();
 
@@ -21,7 +24,8 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
-pub fn solve_b(_input: &str) -> u128 {
+#[solver(year = 2023, day = 19, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {
     // let key_instructions_map = input
     //     .split("\r\n\r\n")
     //     .next()
@@ -33,7 +37,7 @@ pub fn solve_b(_input: &str) -> u128 {
 
 
 
-    0
+    NotImplemented
 }
 
 fn get_value_for_part(part: Part, key_instructions_map: &HashMap<String, Instructions>) -> usize {
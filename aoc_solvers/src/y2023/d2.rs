@@ -1,3 +1,6 @@
+use proc_macros::solver;
+
+#[solver(year = 2023, day = 2, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -7,6 +10,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2023, day = 2, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .lines()
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use crate::y2023::d20::ModuleType::*;
 use crate::y2023::d20::Pulse::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 20, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut configuration = Configuration::from(input);
     let (low, high) = configuration.push_button_times(1000);
@@ -9,6 +11,7 @@ pub fn solve_a(input: &str) -> usize {
 }
 
 /// TODO naive and slow, improve!
+#[solver(year = 2023, day = 20, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut configuration = Configuration::from(input);
     configuration.count_times_to_reach_rx()
@@ -1,20 +1,26 @@
 use std::collections::HashSet;
-use itertools::Itertools;
 use Tile::*;
 use helpers::prelude::*;
+use crate::PuzzleContext;
+use crate::NotImplemented;
+use proc_macros::solver;
 
-/// Hint: I added the number of taken steps to my puzzle input (first line)
-pub fn solve_a(input: &str) -> usize {
-    let mut lines = input.lines();
-    let num_steps = lines.next().unwrap().parse::<usize>().unwrap();
-    let mut tile_map = TileMap::<Tile>::from(lines.join("\n").as_str());
+/// The puzzle takes 64 steps, the example sets its own number of steps as the parameter steps
+#[solver(year = 2023, day = 21, part = a)]
+pub fn solve_a(
+    input: &str,
+    context: &PuzzleContext,
+) -> usize {
+    let num_steps = context.params().get_or("steps", 64);
+    let mut tile_map = TileMap::<Tile>::from(input);
 
     count_visited_tiles(num_steps, &mut tile_map)
 }
 
 /// No idea so far. Maybe find out a pattern and skip ahead
-pub fn solve_b(_input: &str) -> usize {
-    0
+#[solver(year = 2023, day = 21, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {
+    NotImplemented
 }
 
 fn count_visited_tiles(num_steps: usize, tile_map: &mut TileMap<Tile>) -> usize {
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 3, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let pos_char_map = create_pos_char_map(input);
 
@@ -54,6 +56,7 @@ pub fn solve_a(input: &str) -> usize {
     number_matches.into_iter().sum()
 }
 
+#[solver(year = 2023, day = 3, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let pos_char_map = create_pos_char_map(input);
 
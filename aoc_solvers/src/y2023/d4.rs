@@ -1,5 +1,7 @@
 use std::collections::HashMap;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 4, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -8,6 +10,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2023, day = 4, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let cards = input
         .lines()
@@ -1,5 +1,7 @@
 use rayon::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 5, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let seeds = parse_seeds(input.lines().next().unwrap());
 
@@ -11,6 +13,8 @@ pub fn solve_a(input: &str) -> usize {
         .min().unwrap()
 }
 
+/// Brute forces every seed, so it gets more time than the default timeout.
+#[solver(year = 2023, day = 5, part = b, timeout = 600)]
 pub fn solve_b(input: &str) -> usize {
     let seeds = parse_seeds(input.lines().next().unwrap());
     let mappings = collect_mappings(input);
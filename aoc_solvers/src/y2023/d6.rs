@@ -1,3 +1,6 @@
+use proc_macros::solver;
+
+#[solver(year = 2023, day = 6, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let times = input
         .lines()
@@ -31,6 +34,7 @@ pub fn solve_a(input: &str) -> usize {
         .product()
 }
 
+#[solver(year = 2023, day = 6, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let time = input
         .lines()
@@ -2,7 +2,9 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use HandType::*;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 7, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut hands = input.lines().map(Hand::from_line).collect::<Vec<_>>();
 
@@ -11,6 +13,7 @@ pub fn solve_a(input: &str) -> usize {
     hands.iter().enumerate().map(|(i, h)| (i + 1) * h.bid).sum()
 }
 
+#[solver(year = 2023, day = 7, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut hands = input.lines().map(Hand::from_line_with_joker).collect::<Vec<_>>();
 
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 use num::Integer;
+use proc_macros::solver;
 
+#[solver(year = 2023, day = 8, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let instructions = input.lines().next().unwrap().chars().collect::<Vec<_>>();
 
@@ -38,6 +40,7 @@ pub fn solve_a(input: &str) -> usize {
     count
 }
 
+#[solver(year = 2023, day = 8, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let instructions = input.lines().next().unwrap().chars().collect::<Vec<_>>();
 
@@ -1,3 +1,6 @@
+use proc_macros::solver;
+
+#[solver(year = 2023, day = 9, part = a)]
 pub fn solve_a(input: &str) -> isize {
     input
         .lines()
@@ -7,6 +10,7 @@ pub fn solve_a(input: &str) -> isize {
         .sum()
 }
 
+#[solver(year = 2023, day = 9, part = b)]
 pub fn solve_b(input: &str) -> isize {
     input
         .lines()
@@ -1,7 +1,9 @@
 use helpers::prelude::*;
 use std::collections::HashMap;
 use regex::Regex;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 1, part = a)]
 pub fn solve_a(input: &str) -> isize {
     let num_lines = input.lines().count();
 
@@ -29,6 +31,7 @@ pub fn solve_a(input: &str) -> isize {
         .sum()
 }
 
+#[solver(year = 2024, day = 1, part = b)]
 pub fn solve_b(input: &str) -> isize {
     let num_lines = input.lines().count();
 
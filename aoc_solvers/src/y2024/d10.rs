@@ -1,6 +1,8 @@
 use helpers::prelude::*;
 use std::collections::HashSet;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 10, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let board = Board::<usize>::from_str_using_mapping(input, |c| c.to_digit(10).unwrap() as usize).unwrap();
     board
@@ -37,6 +39,7 @@ fn add_reachable_ends(
     }
 }
 
+#[solver(year = 2024, day = 10, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let board = Board::<usize>::from_str_using_mapping(input, |c| c.to_digit(10).unwrap() as usize).unwrap();
     board
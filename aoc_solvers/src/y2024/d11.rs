@@ -1,6 +1,8 @@
 use helpers::prelude::*;
 use std::collections::HashMap;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 11, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut cache = HashMap::new();
 
@@ -15,6 +17,7 @@ pub fn solve_a(input: &str) -> usize {
 /// From Past-Me: The trick to solving this one was to ask myself "how many different numbers do actually occur?",
 /// which the answer was "not so many!". A single number like 125 just produces around 50 different numbers.
 /// This is small enough to just cache results.
+#[solver(year = 2024, day = 11, part = b)]
 pub fn solve_b(input: &str) -> u128 {
     let mut cache = HashMap::new();
 
@@ -1,7 +1,9 @@
 use helpers::prelude::*;
 use std::collections::HashSet;
 use std::fmt::Formatter;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 12, part = a)]
 pub fn solve_a(input: &str) -> usize {
     collect_plots(input)
         .into_iter()
@@ -9,6 +11,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2024, day = 12, part = b)]
 pub fn solve_b(input: &str) -> usize {
     collect_plots(input)
         .into_iter()
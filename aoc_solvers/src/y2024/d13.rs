@@ -1,7 +1,9 @@
 use std::fmt::Formatter;
 use regex::Regex;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 13, part = a)]
 pub fn solve_a(input: &str) -> usize {
     parse_input(input)
         .into_iter()
@@ -9,6 +11,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2024, day = 13, part = b)]
 pub fn solve_b(input: &str) -> u128 {
     parse_input(input)
         .into_iter()
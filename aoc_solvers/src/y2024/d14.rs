@@ -2,38 +2,58 @@ use helpers::prelude::*;
 use indoc::indoc;
 use regex::Regex;
 use std::fmt::Formatter;
+use crate::{Params, PuzzleContext, Solver};
+use proc_macros::solver;
+
+pub struct Day14;
+
+#[solver(year = 2024, day = 14)]
+impl Solver for Day14 {
+    type Parsed = Vec<Robot>;
+    type A = usize;
+    type B = usize;
+
+    fn parse(input: &str) -> Vec<Robot> {
+        let robot_regex = Regex::new(r#"p=(\d+),(\d+)\sv=(-?\d+),(-?\d+)"#).unwrap();
+
+        regex_captures(
+            input,
+            &robot_regex,
+            |caps| Robot {
+                start: p!(parse::<isize>(caps[0]), parse::<isize>(caps[1])),
+                vel: (parse::<isize>(caps[2]), parse::<isize>(caps[3])),
+            },
+        ).into_iter().collect()
+    }
 
-pub fn solve_a(input: &str) -> usize {
-    // I manually added the dimension of the board to my puzzle/example input to vary between the 2
-    let dim_regex = Regex::new(r#"(\d+),(\d+)"#).unwrap();
-    let robot_regex = Regex::new(r#"p=(\d+),(\d+)\sv=(-?\d+),(-?\d+)"#).unwrap();
+    fn part_a(
+        robots: &Vec<Robot>,
+        context: &PuzzleContext,
+    ) -> usize {
+        solve_a(robots, context)
+    }
 
-    let dim_line = input.lines().next().unwrap();
-    let dim: (isize, isize) = regex_captures(
-        dim_line,
-        &dim_regex,
-        |caps| (parse(caps[0]), parse(caps[1])),
-    ).into_iter().next().unwrap();
+    fn part_b(
+        robots: &Vec<Robot>,
+        context: &PuzzleContext,
+    ) -> usize {
+        solve_b(robots, context)
+    }
+}
 
-    let robot_input = input
-        .lines()
-        .skip(1)
-        .collect::<String>();
+fn solve_a(
+    robots: &[Robot],
+    context: &PuzzleContext,
+) -> usize {
+    let dim = dimension(context.params());
 
     let q1 = Bounds::new(0, 0, dim.0 / 2 - 1, dim.1 / 2 - 1);
     let q2 = Bounds::new(0, dim.1 / 2 + 1, dim.0 / 2 - 1, dim.1 - 1);
     let q3 = Bounds::new(dim.0 / 2 + 1, 0, dim.0 - 1, dim.1 / 2 - 1);
     let q4 = Bounds::new(dim.0 / 2 + 1, dim.1 / 2 + 1, dim.0 - 1, dim.1 - 1);
 
-    let res = regex_captures(
-        &robot_input,
-        &robot_regex,
-        |caps| Robot {
-            start: p!(parse::<isize>(caps[0]), parse::<isize>(caps[1])),
-            vel: (parse::<isize>(caps[2]), parse::<isize>(caps[3])),
-        },
-    )
-        .into_iter()
+    let res = robots
+        .iter()
         .map(|r| (0..100)
             .fold(r.start, |acc, _| {
                 let mut new_pos = acc + r.vel;
@@ -74,35 +94,15 @@ pub fn solve_a(input: &str) -> usize {
     res.0 * res.1 * res.2 * res.3
 }
 
-pub fn solve_b(input: &str) -> usize {
-    let dim_regex = Regex::new(r#"(\d+),(\d+)"#).unwrap();
-    let robot_regex = Regex::new(r#"p=(\d+),(\d+)\sv=(-?\d+),(-?\d+)"#).unwrap();
-
-    let dim_line = input.lines().next().unwrap();
-    let dim: (isize, isize) = regex_captures(
-        dim_line,
-        &dim_regex,
-        |caps| (parse(caps[0]), parse(caps[1])),
-    ).into_iter().next().unwrap();
-
-    let robot_input = input
-        .lines()
-        .skip(1)
-        .collect::<String>();
-
-    let robots = regex_captures(
-        &robot_input,
-        &robot_regex,
-        |caps| Robot {
-            start: p!(parse::<isize>(caps[0]), parse::<isize>(caps[1])),
-            vel: (parse::<isize>(caps[2]), parse::<isize>(caps[3])),
-        },
-    ).into_iter().collect::<Vec<_>>();
+fn solve_b(
+    robots: &[Robot],
+    context: &PuzzleContext,
+) -> usize {
+    let dim = dimension(context.params());
 
     let mut positions = robots.iter().map(|r| r.start).collect::<Vec<_>>();
     let mut count = 0;
     let tree = tree_shape();
-    tree.print();
 
     // For every iteration of robots, create a board from their current positions
     // and check if the shape is in there
@@ -148,6 +148,11 @@ pub fn solve_b(input: &str) -> usize {
     }
 }
 
+/// The width and height of the area the robots move in. The example sets its smaller size as parameters.
+fn dimension(params: &Params) -> (isize, isize) {
+    (params.get_or("width", 101), params.get_or("height", 103))
+}
+
 fn tree_shape() -> Shape {
     let tree_string = indoc! {"
         XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX
@@ -188,7 +193,7 @@ fn tree_shape() -> Shape {
     Shape::from(tree_string)
 }
 
-struct Robot {
+pub struct Robot {
     start: Position,
     vel: (isize, isize),
 }
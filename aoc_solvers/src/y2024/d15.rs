@@ -1,6 +1,8 @@
 use helpers::prelude::*;
 use itertools::Itertools;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 15, part = a)]
 pub fn solve_a(input: &str) -> usize {
     use TileA::*;
 
@@ -75,6 +77,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2024, day = 15, part = b)]
 pub fn solve_b(input: &str) -> usize {
     use TileB::*;
 
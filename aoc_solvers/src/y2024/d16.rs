@@ -2,7 +2,9 @@ use std::collections::HashSet;
 use helpers::prelude::*;
 use Tile::*;
 use pathfinding::prelude::{astar, astar_bag_collect};
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 16, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
     let start = (board.get_positions_of(&Start).next().unwrap(), XP);
@@ -35,6 +37,7 @@ pub fn solve_a(input: &str) -> usize {
     cost
 }
 
+#[solver(year = 2024, day = 16, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
     let start = (board.get_positions_of(&Start).next().unwrap(), XP);
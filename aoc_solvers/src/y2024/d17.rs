@@ -1,7 +1,9 @@
 use helpers::prelude::*;
 use itertools::Itertools;
 use Ins::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 17, part = a)]
 pub fn solve_a(input: &str) -> String {
     Device::new(input)
         .run()
@@ -10,6 +12,7 @@ pub fn solve_a(input: &str) -> String {
         .join(",")
 }
 
+#[solver(year = 2024, day = 17, part = b)]
 pub fn solve_b(input: &str) -> usize {
     Device::new(input).find_a_producing_input()
 }
@@ -1,7 +1,9 @@
 use helpers::prelude::*;
 use Tile::*;
 use pathfinding::prelude::astar;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 18, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut lines = input.lines();
     // I added the board dimension and the amount of bytes falling to my puzzle/example input
@@ -44,6 +46,7 @@ pub fn solve_a(input: &str) -> usize {
     length
 }
 
+#[solver(year = 2024, day = 18, part = b)]
 pub fn solve_b(input: &str) -> String {
     let mut lines = input.lines();
     let (dimension, fallen_bytes) = {
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use crate::y2024::d19::Color::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 19, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut split = input.split("\n\n");
 
@@ -21,6 +23,7 @@ pub fn solve_a(input: &str) -> usize {
         .count()
 }
 
+#[solver(year = 2024, day = 19, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut split = input.split("\n\n");
 
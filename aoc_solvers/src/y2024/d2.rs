@@ -1,23 +1,32 @@
 use helpers::prelude::*;
+use proc_macros::solver;
 
-pub fn solve_a(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| line.split(" ").map(parse::<usize>).collect::<Vec<_>>())
+#[solver(year = 2024, day = 2, part = a)]
+pub fn solve_a(input: &str) -> Result<usize, SolveError> {
+    let count = parse_reports(input)?
+        .into_iter()
         .filter(|nums| is_safe(nums))
-        .count()
+        .count();
+
+    Ok(count)
 }
 
-pub fn solve_b(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| line.split(" ").map(parse::<usize>).collect::<Vec<_>>())
+#[solver(year = 2024, day = 2, part = b)]
+pub fn solve_b(input: &str) -> Result<usize, SolveError> {
+    let count = parse_reports(input)?
+        .into_iter()
         .filter(|nums| (0..nums.len()).any(|i| {
             let mut clone = nums.clone();
             clone.remove(i);
             is_safe(&clone)
         }))
-        .count()
+        .count();
+
+    Ok(count)
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<usize>>, SolveError> {
+    try_parse_lines(input, |line| line.split(" ").map(try_parse::<usize>).collect())
 }
 
 fn is_safe(nums: &[usize]) -> bool {
@@ -1,64 +1,63 @@
 use Tile::*;
 use helpers::prelude::*;
 use pathfinding::prelude::astar;
-use itertools::Itertools;
 use rayon::prelude::*;
+use crate::{PuzzleContext, Solver};
+use proc_macros::solver;
 
-pub fn solve_a(input: &str) -> usize {
-    // I added the target threshold to the puzzle input
-    let threshold = parse(input.lines().next().unwrap());
-    let board_input = input.lines().skip(1).join("\n");
-    let board = Board::<Tile>::from(board_input.as_str());
+pub struct Day20;
 
-    let start = board.get_positions_of(&Start).next().unwrap();
-    let goal = board.get_positions_of(&End).next().unwrap();
+#[solver(year = 2024, day = 20)]
+impl Solver for Day20 {
+    type Parsed = Track;
+    type A = usize;
+    type B = usize;
 
-    // get the best path and its len when using no cheats
-    let (path, len) = astar(
-        &start,
-        |pos| pos
-            .cardinal_neighbours()
-            .into_iter()
-            .filter(|n| *board.get_tile(*n).unwrap() != Wall)
-            .map(|n| (n, 1)),
-        |pos| pos.manhattan_distance(&goal) as usize,
-        |pos| *pos == goal,
-    ).unwrap();
+    fn parse(input: &str) -> Track {
+        let board = Board::<Tile>::from(input);
 
-    count_cheats(
-        len,
-        &path,
-        2,
-        threshold,
-    )
-}
+        let start = board.get_positions_of(&Start).next().unwrap();
+        let goal = board.get_positions_of(&End).next().unwrap();
+
+        // get the best path and its len when using no cheats
+        let (path, len) = astar(
+            &start,
+            |pos| pos
+                .cardinal_neighbours()
+                .into_iter()
+                .filter(|n| *board.get_tile(*n).unwrap() != Wall)
+                .map(|n| (n, 1)),
+            |pos| pos.manhattan_distance(&goal) as usize,
+            |pos| *pos == goal,
+        ).unwrap();
 
-pub fn solve_b(input: &str) -> usize {
-    // I added the target threshold to the puzzle input
-    let threshold = parse(input.lines().next().unwrap());
-    let board_input = input.lines().skip(1).join("\n");
-    let board = Board::<Tile>::from(board_input.as_str());
-    let start = board.get_positions_of(&Start).next().unwrap();
-    let goal = board.get_positions_of(&End).next().unwrap();
+        Track { path, len }
+    }
 
-    // get the best path and its len when using no cheats
-    let (path, len) = astar(
-        &start,
-        |pos| pos
-            .cardinal_neighbours()
-            .into_iter()
-            .filter(|n| *board.get_tile(*n).unwrap() != Wall)
-            .map(|n| (n, 1)),
-        |pos| pos.manhattan_distance(&goal) as usize,
-        |pos| *pos == goal,
-    ).unwrap();
+    fn part_a(
+        track: &Track,
+        context: &PuzzleContext,
+    ) -> usize {
+        // The puzzle counts cheats which save at least 100 picoseconds, the examples set their own threshold
+        let threshold = context.params().get_or("threshold", 100);
+
+        count_cheats(track.len, &track.path, 2, threshold)
+    }
+
+    fn part_b(
+        track: &Track,
+        context: &PuzzleContext,
+    ) -> usize {
+        let threshold = context.params().get_or("threshold", 100);
+
+        count_cheats(track.len, &track.path, 20, threshold)
+    }
+}
 
-    count_cheats(
-        len,
-        &path,
-        20,
-        threshold,
-    )
+/// The shortest path through the race track when using no cheats.
+pub struct Track {
+    path: Vec<Position>,
+    len: usize,
 }
 
 fn count_cheats(
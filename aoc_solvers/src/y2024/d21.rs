@@ -3,7 +3,9 @@ use std::fmt::Formatter;
 use helpers::prelude::*;
 use KeypadButton::*;
 use ControlButton::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 21, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -32,7 +34,7 @@ fn get_required_inputs_for_keypad(mut keypad_inputs: Vec<KeypadButton>) -> Vec<C
         .flat_map(|w| get_keypad_inputs_from_to(w[0], w[1]))
         .collect();
 
-    println!("{res:?}");
+    trace!("{res:?}");
 
     res
 }
@@ -95,7 +97,7 @@ fn get_required_inputs_for_control(mut control_inputs: Vec<ControlButton>) -> Ve
         .flat_map(|w| get_control_inputs_from_to(w[0], w[1]))
         .collect();
 
-    println!("{res:?}");
+    trace!("{res:?}");
 
     res
 }
@@ -151,6 +153,7 @@ fn get_control_inputs_from_to(current: ControlButton, target: ControlButton) ->
     inputs
 }
 
+#[solver(year = 2024, day = 21, part = b)]
 pub fn solve_b(input: &str) -> usize {
     // todo current state: The idea is simple: transform the current version of A into a recursive version,
     //  use a cache and call it until depth 25. The problem: I just don't get it how to implement this
@@ -177,7 +180,7 @@ fn get_num_keypad_presses(inputs: Vec<KeypadButton>, depth: usize) -> usize {
     let inputs = get_required_inputs_for_keypad(inputs);
     let mut cache = HashMap::new();
 
-    println!("{inputs:?}");
+    trace!("{inputs:?}");
 
     //inputs.insert(0, Activate);
 
@@ -187,7 +190,7 @@ fn get_num_keypad_presses(inputs: Vec<KeypadButton>, depth: usize) -> usize {
         .map(|(i, w)| get_num_control_presses(w[0], w[1], i == 0, depth, &mut cache))
         .sum();
 
-    println!("{sum}");
+    debug!("{sum}");
 
     sum
 }
@@ -204,7 +207,7 @@ fn get_num_control_presses(
 
     match amount_opt {
         Some(amount) => {
-            println!("Returning cached value for: {:?}, amount: {amount}", (current, target, current_depth));
+            trace!("Returning cached value for: {:?}, amount: {amount}", (current, target, current_depth));
             amount
         }
         None => {
@@ -218,7 +221,7 @@ fn get_num_control_presses(
                     get_control_inputs_from_to(current, target)
                 };
 
-                println!("({current:?} to {target:?}), Inputs: {inputs:?}, Depth: {current_depth}");
+                trace!("({current:?} to {target:?}), Inputs: {inputs:?}, Depth: {current_depth}");
 
                 inputs.len()
             } else {
@@ -232,7 +235,7 @@ fn get_num_control_presses(
                     get_control_inputs_from_to(current, target)
                 };
 
-                println!("({current:?} to {target:?}), Inputs: {inputs:?}, Depth: {current_depth}");
+                trace!("({current:?} to {target:?}), Inputs: {inputs:?}, Depth: {current_depth}");
 
                 // todo breaks when input is only one element long
 
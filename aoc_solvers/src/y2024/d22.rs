@@ -1,6 +1,8 @@
 use helpers::prelude::*;
 use std::collections::HashMap;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 22, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -9,6 +11,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2024, day = 22, part = b)]
 pub fn solve_b(input: &str) -> usize {
     // the price is the first digit of the given number
     let get_price = |num: usize| num % 10;
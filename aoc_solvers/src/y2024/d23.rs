@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 use itertools::Itertools;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 23, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let connections = input
         .lines()
@@ -45,6 +47,7 @@ pub fn solve_a(input: &str) -> usize {
         .count()
 }
 
+#[solver(year = 2024, day = 23, part = b)]
 pub fn solve_b(input: &str) -> String {
     let connections = input
         .lines()
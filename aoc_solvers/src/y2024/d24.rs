@@ -3,13 +3,17 @@ use std::fmt::Formatter;
 use regex::Regex;
 use helpers::prelude::*;
 use Operator::*;
+use crate::NotImplemented;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 24, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let device = Device::from_input(input);
     device.calculate_z()
 }
 
-pub fn solve_b(input: &str) -> usize {
+#[solver(year = 2024, day = 24, part = b)]
+pub fn solve_b(input: &str) -> NotImplemented {
     // todo I still have no idea, maybe this will help if I find the energy to try this again: https://www.reddit.com/r/adventofcode/comments/1hla5ql/2024_day_24_part_2_a_guide_on_the_idea_behind_the/
 
     let device = Device::from_input(input);
@@ -17,13 +21,13 @@ pub fn solve_b(input: &str) -> usize {
     let x = device.get_x();
     let y = device.get_y();
 
-    println!("X: {x}, Y: {y}");
+    debug!("X: {x}, Y: {y}");
     let sum = x + y;
-    println!("Sum: {sum}");
+    debug!("Sum: {sum}");
     let current_z = device.calculate_z();
-    println!("Z: {current_z}");
-    println!("Sum Binary: {sum:#046b}");
-    println!("Z   Binary: {current_z:#046b}");
+    debug!("Z: {current_z}");
+    debug!("Sum Binary: {sum:#046b}");
+    debug!("Z   Binary: {current_z:#046b}");
 
     let all_gates = device
         .z_targets()
@@ -34,10 +38,9 @@ pub fn solve_b(input: &str) -> usize {
                 map
             });
 
-            println!("{target}");
+            trace!("{target}");
             for i in 0..grouped.len() {
-                grouped.get(&i).unwrap().iter().for_each(|gate| print!("{gate}    "));
-                println!()
+                trace!("{}", grouped.get(&i).unwrap().iter().map(|gate| format!("{gate}    ")).collect::<String>());
             }
 
             //println!("{target}: Gates: {:?}, Num Gates: {}", gates, gates.len())
@@ -47,7 +50,7 @@ pub fn solve_b(input: &str) -> usize {
             all_gates
         });
 
-    println!("All gates: {}", all_gates.len());
+    debug!("All gates: {}", all_gates.len());
 
     //let mut xs = all_gates.iter().flat_map(|(_, gate)| [gate.a.clone(), gate.b.clone()]).filter(|origin| origin.starts_with("x")).collect::<Vec<_>>();
     //xs.sort();
@@ -70,7 +73,7 @@ pub fn solve_b(input: &str) -> usize {
     //    .flat_map(|target| get_sub_gates(target, &target_expressions_map))
     //    .collect::<HashSet<_>>();
 
-    0
+    NotImplemented
 }
 
 struct Device {
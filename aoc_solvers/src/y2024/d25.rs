@@ -1,7 +1,9 @@
 use helpers::prelude::*;
 use Tile::*;
-use proc_macros::tile;
+use crate::NotImplemented;
+use proc_macros::{solver, tile};
 
+#[solver(year = 2024, day = 25, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut locks = vec![];
     let mut keys = vec![];
@@ -21,8 +23,9 @@ pub fn solve_a(input: &str) -> usize {
         .count()
 }
 
-pub fn solve_b(_input: &str) -> usize {
-    0
+#[solver(year = 2024, day = 25, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {
+    NotImplemented
 }
 
 fn column_counts(board: &Board<Tile>) -> [usize; 5] {
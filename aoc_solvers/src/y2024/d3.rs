@@ -1,7 +1,9 @@
 use std::str::FromStr;
 use regex::Regex;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 3, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let regex = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
 
@@ -16,6 +18,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2024, day = 3, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let regex = Regex::new(r"(mul\(\d+,\d+\)|do\(\)|don't\(\))").unwrap();
 
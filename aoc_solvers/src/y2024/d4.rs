@@ -1,6 +1,8 @@
 use helpers::prelude::*;
 use pad::p;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 4, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let tiles = input
         .lines()
@@ -33,6 +35,7 @@ fn amount_xmas_at_position(tiles: &[Vec<char>], pos: Position) -> usize {
     amount
 }
 
+#[solver(year = 2024, day = 4, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let tiles = input
         .lines()
@@ -1,7 +1,9 @@
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 5, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let split = input.split("\n\n").collect::<Vec<_>>();
     let ordering = split[0];
@@ -25,6 +27,7 @@ pub fn solve_a(input: &str) -> usize {
 }
 
 
+#[solver(year = 2024, day = 5, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let split = input.split("\n\n").collect::<Vec<_>>();
     let ordering = split[0];
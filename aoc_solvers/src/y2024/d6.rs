@@ -2,7 +2,9 @@ use std::collections::HashSet;
 use helpers::prelude::*;
 use rayon::iter::ParallelIterator;
 use rayon::iter::ParallelBridge;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 6, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let (board, guardians) = Board::board_and_specials_from_str(input, |c, pos| match c {
         '^' => Some((Guardian::new(pos), Tile::Free)),
@@ -25,6 +27,7 @@ pub fn solve_a(input: &str) -> usize {
     }
 }
 
+#[solver(year = 2024, day = 6, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let (board, guardians) = Board::board_and_specials_from_str(input, |c, pos| match c {
         '^' => Some((Guardian::new(pos), Tile::Free)),
@@ -2,7 +2,9 @@ use std::str::FromStr;
 use rayon::iter::ParallelIterator;
 use rayon::iter::ParallelBridge;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 7, part = a)]
 pub fn solve_a(input: &str) -> usize {
     // solve with simple brute force
     input
@@ -45,6 +47,7 @@ fn permutations_2_ops(num_len: usize) -> impl IntoIterator<Item=usize> {
     0..2_usize.pow((num_len - 1) as u32)
 }
 
+#[solver(year = 2024, day = 7, part = b)]
 pub fn solve_b(input: &str) -> usize {
     // solve with simple brute force, again
     input
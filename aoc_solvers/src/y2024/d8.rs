@@ -1,7 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use itertools::Itertools;
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 8, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
     // group positions by frequency
@@ -33,6 +35,7 @@ pub fn solve_a(input: &str) -> usize {
     antinodes.len()
 }
 
+#[solver(year = 2024, day = 8, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
     // group positions by frequency
@@ -1,6 +1,8 @@
 use std::fmt::{Debug, Formatter};
 use Entry::*;
+use proc_macros::solver;
 
+#[solver(year = 2024, day = 9, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut file_system = input
         .chars()
@@ -47,6 +49,7 @@ fn compress(file_system: &mut [Entry]) {
     }
 }
 
+#[solver(year = 2024, day = 9, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut file_system = input
         .chars()
@@ -1,5 +1,7 @@
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 1, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut dial = 50;
     let mut times_0 = 0;
@@ -32,6 +34,7 @@ pub fn solve_a(input: &str) -> usize {
     times_0
 }
 
+#[solver(year = 2025, day = 1, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut dial = Dial(50);
     let mut times_0 = 0;
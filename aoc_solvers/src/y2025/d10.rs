@@ -5,11 +5,14 @@ use microlp::{ComparisonOp, LinearExpr, OptimizationDirection, Problem};
 use pathfinding::prelude::dijkstra;
 
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 10, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input.lines().map(Machine::from).map(|m| m.activate()).sum()
 }
 
+#[solver(year = 2025, day = 10, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .lines()
@@ -1,21 +1,48 @@
 use std::collections::{HashMap, HashSet};
+use crate::{PuzzleContext, Solver};
+use proc_macros::solver;
 
-pub fn solve_a(input: &str) -> usize {
-    // Use dynamic programming to just perform DFS on the graph.
-    // This assumes the graph is a https://en.wikipedia.org/wiki/Directed_acyclic_graph, which it is.
-    
-    let mut edges = HashSet::new();
+pub struct Day11;
+
+#[solver(year = 2025, day = 11)]
+impl Solver for Day11 {
+    /// The edges of the graph, from one device to another
+    type Parsed = HashSet<(String, String)>;
+    type A = usize;
+    type B = usize;
 
-    for line in input.lines() {
-        let split = line.split_once(": ").unwrap();
-        let from = split.0;
+    fn parse(input: &str) -> HashSet<(String, String)> {
+        let mut edges = HashSet::new();
 
-        for s in split.1.split(" ") {
-            edges.insert((from.to_string(), s.to_string()));
+        for line in input.lines() {
+            let split = line.split_once(": ").unwrap();
+            let from = split.0;
+
+            for s in split.1.split(" ") {
+                edges.insert((from.to_string(), s.to_string()));
+            }
         }
+
+        edges
+    }
+
+    fn part_a(
+        edges: &HashSet<(String, String)>,
+        _context: &PuzzleContext,
+    ) -> usize {
+        // Use dynamic programming to just perform DFS on the graph.
+        // This assumes the graph is a https://en.wikipedia.org/wiki/Directed_acyclic_graph, which it is.
+        count_paths("you", "out", edges)
     }
 
-    count_paths("you", "out", &edges)
+    fn part_b(
+        edges: &HashSet<(String, String)>,
+        _context: &PuzzleContext,
+    ) -> usize {
+        // Basically the same as A, but with the fft-dac-constraint and good old memoization.
+        let mut cache = HashMap::new();
+        count_paths_b("svr", "out", false, false, edges, &mut cache)
+    }
 }
 
 fn count_paths(
@@ -36,24 +63,6 @@ fn count_paths(
     sum
 }
 
-pub fn solve_b(input: &str) -> usize {
-    // Basically the same as A, but with the fft-dac-constraint and good old memoization.
-    
-    let mut edges = HashSet::new();
-
-    for line in input.lines() {
-        let split = line.split_once(": ").unwrap();
-        let from = split.0;
-
-        for s in split.1.split(" ") {
-            edges.insert((from.to_string(), s.to_string()));
-        }
-    }
-
-    let mut cache = HashMap::new();
-    count_paths_b("svr", "out", false, false, &edges, &mut cache)
-}
-
 fn count_paths_b(
     current: &str,
     goal: &str,
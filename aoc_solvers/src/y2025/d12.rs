@@ -1,5 +1,8 @@
 use helpers::prelude::*;
+use crate::NotImplemented;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 12, part = a)]
 pub fn solve_a(input: &str) -> usize {
     // Just try to place all shapes using DFS with backtracking
     // 
@@ -122,9 +125,10 @@ fn unplace(
     });
 }
 
-pub fn solve_b(_input: &str) -> usize {
+#[solver(year = 2025, day = 12, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {
     // No part B
-    0
+    NotImplemented
 }
 
 #[derive(Debug)]
@@ -1,5 +1,7 @@
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 2, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .split(",")
@@ -19,6 +21,7 @@ fn invalid_ids(range: (usize, usize)) -> impl Iterator<Item = usize> {
     })
 }
 
+#[solver(year = 2025, day = 2, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .split(",")
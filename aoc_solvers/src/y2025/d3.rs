@@ -1,3 +1,6 @@
+use proc_macros::solver;
+
+#[solver(year = 2025, day = 3, part = a)]
 pub fn solve_a(input: &str) -> usize {
     input
         .lines()
@@ -5,6 +8,7 @@ pub fn solve_a(input: &str) -> usize {
         .sum()
 }
 
+#[solver(year = 2025, day = 3, part = b)]
 pub fn solve_b(input: &str) -> usize {
     input
         .lines()
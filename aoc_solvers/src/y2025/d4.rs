@@ -1,7 +1,8 @@
-use proc_macros::tile;
+use proc_macros::{solver, tile};
 
 use helpers::prelude::*;
 
+#[solver(year = 2025, day = 4, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
 
@@ -19,6 +20,7 @@ pub fn solve_a(input: &str) -> usize {
         .count()
 }
 
+#[solver(year = 2025, day = 4, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut board = Board::<Tile>::from(input);
     let mut removed_count = 0;
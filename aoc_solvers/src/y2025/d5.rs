@@ -1,5 +1,7 @@
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 5, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let split = input.split_once("\n\n").unwrap();
 
@@ -20,6 +22,7 @@ pub fn solve_a(input: &str) -> usize {
         .count()
 }
 
+#[solver(year = 2025, day = 5, part = b)]
 pub fn solve_b(input: &str) -> usize {
     // A very simple approach would be to just collect all the numbers in a set and return its lenght.
     // This, however, takes forever and uses large amounts of RAM. So instead I merge the ranges which overlap
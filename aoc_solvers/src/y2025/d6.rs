@@ -1,7 +1,9 @@
 use itertools::Itertools;
 
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 6, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut lines = input.lines().collect::<Vec<_>>();
     let ops = lines
@@ -34,6 +36,7 @@ pub fn solve_a(input: &str) -> usize {
     sums.iter().sum()
 }
 
+#[solver(year = 2025, day = 6, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let mut lines = input.lines().collect::<Vec<_>>();
     let ops = lines
@@ -1,9 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
-use proc_macros::tile;
+use proc_macros::{solver, tile};
 
 use helpers::prelude::*;
 
+#[solver(year = 2025, day = 7, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let mut board = Board::<Tile>::from(input);
     let mut y = 0;
@@ -50,6 +51,7 @@ pub fn solve_a(input: &str) -> usize {
     split_count
 }
 
+#[solver(year = 2025, day = 7, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let board = Board::<Tile>::from(input);
 
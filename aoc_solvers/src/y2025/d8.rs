@@ -1,8 +1,14 @@
 use std::{cmp::Ordering, collections::HashSet};
 
 use helpers::prelude::*;
-
-pub fn solve_a(input: &str) -> usize {
+use crate::PuzzleContext;
+use proc_macros::solver;
+
+#[solver(year = 2025, day = 8, part = a)]
+pub fn solve_a(
+    input: &str,
+    context: &PuzzleContext,
+) -> usize {
     // Kruskals algorithm, but limit it to a specific number of iterations (10 in the example, 1000 in the puzzle)
 
     let positions = input.lines().map(Pos::new).collect::<Vec<_>>();
@@ -22,13 +28,8 @@ pub fn solve_a(input: &str) -> usize {
         circuits.push(c);
     }
 
-    let iterations = if positions.len() == 1000 {
-        // Puzzle
-        1000
-    } else {
-        // example A
-        10
-    };
+    // The example sets its own number of iterations as a parameter
+    let iterations = context.params().get_or("iterations", 1000);
 
     for edge in edges.into_iter().take(iterations) {
         let ia = circuits
@@ -66,6 +67,7 @@ pub fn solve_a(input: &str) -> usize {
     lens[0] * lens[1] * lens[2]
 }
 
+#[solver(year = 2025, day = 8, part = b)]
 pub fn solve_b(input: &str) -> usize {
     // Just Kruskals algorithm
 
@@ -5,7 +5,9 @@ use rayon::iter::ParallelBridge;
 use rayon::iter::ParallelIterator;
 
 use helpers::prelude::*;
+use proc_macros::solver;
 
+#[solver(year = 2025, day = 9, part = a)]
 pub fn solve_a(input: &str) -> usize {
     let positions = input
         .lines()
@@ -30,6 +32,7 @@ pub fn solve_a(input: &str) -> usize {
         .unwrap()
 }
 
+#[solver(year = 2025, day = 9, part = b)]
 pub fn solve_b(input: &str) -> usize {
     let positions = input
         .lines()
@@ -1,6 +1,9 @@
+mod ocr;
 mod parse_to_num;
 pub mod prelude;
 mod regex_captures;
 mod run_with_cycle;
+mod solve_error;
 mod string_helpers;
 mod tile_map;
+pub mod trace;
@@ -0,0 +1,140 @@
+//! Recognition of the block letters some puzzles draw as their answer, like the CRT image of 2022 day 10.
+//!
+//! Two fonts are known: letters which are 4 pixels wide and 6 pixels high, separated by 1 empty column,
+//! and letters which are 6 pixels wide and 10 pixels high, separated by 2 empty columns. The font is
+//! chosen by the height of the image, which must start with the first letter in its top left corner.
+
+use crate::prelude::*;
+
+/// The letters of the small font, which are at most 5 pixels wide and 6 pixels high.
+const SMALL_FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The letters of the large font, which are 6 pixels wide and 10 pixels high.
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Read the letters of the given image. The pixel at (x, y) is lit if the given function returns true.
+/// Fails if the height does not match a font or a letter is not known.
+pub fn ocr(
+    width: usize,
+    height: usize,
+    is_lit: impl Fn(usize, usize) -> bool,
+) -> Result<String, String> {
+    // Every letter covers its own columns and the empty columns after it
+    let letter_width = match height {
+        6 => 5,
+        10 => 8,
+        _ => return Err(format!("no font has letters which are {height} pixels high, expected 6 or 10")),
+    };
+
+    (0..width.div_ceil(letter_width))
+        .map(|letter| {
+            let left = letter * letter_width;
+            let rows = (0..height)
+                .map(|y| {
+                    (left..left + letter_width)
+                        .map(|x| if x < width && is_lit(x, y) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+
+            let letter = match height {
+                6 => find_letter(&SMALL_FONT, &rows),
+                _ => find_letter(&LARGE_FONT, &rows),
+            };
+
+            letter.ok_or_else(|| format!("the letter at column {left} is not known"))
+        })
+        .collect()
+}
+
+/// Find the letter of the given font which is drawn in the given rows. Nothing may be drawn right of the letter.
+fn find_letter<const HEIGHT: usize>(
+    font: &[(char, [&str; HEIGHT])],
+    rows: &[String],
+) -> Option<char> {
+    font.iter()
+        .find(|(_, letter)| {
+            letter
+                .iter()
+                .zip(rows)
+                .all(|(letter_row, row)| row.strip_prefix(letter_row).is_some_and(|rest| !rest.contains('#')))
+        })
+        .map(|(c, _)| *c)
+}
+
+/// Read the letters of the given board. A tile is lit if the given function returns true for it.
+pub fn ocr_board<T>(
+    board: &Board<T>,
+    is_lit: impl Fn(&T) -> bool,
+) -> Result<String, String> {
+    ocr(board.width, board.height, |x, y| board.get_tile(p!(x, y)).is_some_and(&is_lit))
+}
+
+/// Read the letters of the given tile map. A tile is lit if the given function returns true for it.
+pub fn ocr_tile_map<T: Copy>(
+    tile_map: &TileMap<T>,
+    is_lit: impl Fn(T) -> bool,
+) -> Result<String, String> {
+    ocr(tile_map.width, tile_map.height, |x, y| tile_map.try_get(p!(x, y)).is_some_and(&is_lit))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ocr::{ocr, ocr_tile_map};
+    use crate::tile_map::TileMap;
+
+    #[test]
+    fn letters_are_recognized() {
+        let image = "\
+###..#..#.###..#..#.####.#..#.####.###..
+#..#.#..#.#..#.#..#.#....#.#..#....#..#.
+#..#.#..#.#..#.####.###..##...###..#..#.
+###..#..#.###..#..#.#....#.#..#....###..
+#.#..#..#.#....#..#.#....#.#..#....#.#..
+#..#..##..#....#..#.####.#..#.####.#..#.";
+        let tile_map = TileMap::<char>::from(image);
+
+        assert_eq!(ocr_tile_map(&tile_map, |c| c == '#'), Ok("RUPHEKER".to_string()));
+
+        let lines = image.lines().collect::<Vec<_>>();
+        let lit = |x: usize, y: usize| lines[y].as_bytes()[x] == b'#';
+        assert_eq!(ocr(10, 6, lit), Ok("RU".to_string()));
+        assert!(ocr(40, 5, lit).is_err());
+        assert!(ocr(40, 6, |x, _| x == 0).is_err());
+    }
+}
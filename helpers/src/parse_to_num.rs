@@ -1,3 +1,6 @@
+use crate::solve_error::SolveError;
+use std::any::type_name;
+use std::error::Error;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -10,3 +13,20 @@ where T: FromStr, <T as FromStr>::Err: Debug {
 pub fn parse_char(input: char) -> u32 {
     input.to_digit(10).unwrap_or_else(|| panic!("{input} could not be parsed"))
 }
+
+/// Like [parse], but returns an error which knows the text that could not be parsed instead of panicking.
+pub fn try_parse<T>(input: &str) -> Result<T, SolveError>
+where T: FromStr, <T as FromStr>::Err: Error + Send + Sync + 'static {
+    input.parse::<T>().map_err(|e| {
+        SolveError::new(format!("'{input}' is not a valid {}", type_name::<T>()))
+            .with_snippet(input)
+            .with_source(e)
+    })
+}
+
+/// Like [parse_char], but returns an error instead of panicking.
+pub fn try_parse_char(input: char) -> Result<u32, SolveError> {
+    input
+        .to_digit(10)
+        .ok_or_else(|| SolveError::new(format!("'{input}' is not a digit")).with_snippet(input))
+}
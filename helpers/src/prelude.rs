@@ -7,9 +7,13 @@ pub use pad::p;
 pub use pad::position::*;
 pub use pad::shape::*;
 
+pub use crate::ocr::*;
 pub use crate::parse_to_num::*;
 pub use crate::regex_captures::*;
 pub use crate::run_with_cycle::*;
+pub use crate::solve_error::*;
 pub use crate::string_helpers::*;
 // todo remove and replace with the Board from pad. This way, I could remove the itertools dependency
 pub use crate::tile_map::*;
+pub use crate::debug;
+pub use crate::trace::trace;
@@ -0,0 +1,146 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error of a solver, like malformed input. Knows the text which caused it and where it is located
+/// in the input, if this is known.
+#[derive(Debug)]
+pub struct SolveError {
+    message: String,
+    /// The text which caused the error, like a number which could not be parsed
+    snippet: Option<String>,
+    /// The line of the input, starting at 1
+    line: Option<usize>,
+    /// The column in the line, starting at 1
+    column: Option<usize>,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            message: message.into(),
+            snippet: None,
+            line: None,
+            column: None,
+            source: None,
+        }
+    }
+
+    /// Set the text which caused the error.
+    pub fn with_snippet(
+        mut self,
+        snippet: impl Into<String>,
+    ) -> Self {
+        self.snippet = Some(snippet.into());
+        self
+    }
+
+    /// Set the line of the input the error occurred in, starting at 1.
+    pub fn at_line(
+        mut self,
+        line: usize,
+    ) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Set the column in the line the error occurred at, starting at 1.
+    pub fn at_column(
+        mut self,
+        column: usize,
+    ) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Set the error which caused this one.
+    pub fn with_source(
+        mut self,
+        source: impl Error + Send + Sync + 'static,
+    ) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// Set the location of the error from the given line of the input, unless the location is already set.
+    /// The column is the position of the snippet in the line, if it can be found.
+    fn in_line(
+        mut self,
+        number: usize,
+        line: &str,
+    ) -> Self {
+        if self.line.is_none() {
+            self.line = Some(number);
+            self.column = self
+                .column
+                .or_else(|| line.find(self.snippet.as_deref()?).map(|index| line[..index].chars().count() + 1));
+        }
+
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: {}", self.message),
+            (Some(line), None) => write!(f, "line {line}: {}", self.message),
+            (None, Some(column)) => write!(f, "column {column}: {}", self.message),
+            (None, None) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+/// Map every line of the given input with the given fallible function. An error is located at the line it
+/// occurred in, so the message tells which part of the input is malformed.
+pub fn try_parse_lines<T>(
+    input: &str,
+    mapper: impl Fn(&str) -> Result<T, SolveError>,
+) -> Result<Vec<T>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| mapper(line).map_err(|e| e.in_line(index + 1, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::error::Error;
+
+    #[test]
+    fn errors_are_located_in_the_input() {
+        let error = try_parse_lines("1 2\n3 x4\n", |line| {
+            line.split_whitespace()
+                .map(try_parse::<u32>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2, column 3: 'x4' is not a valid u32");
+        assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+        assert_eq!(
+            try_parse_lines("12\n3", |line| line.chars().map(try_parse_char).sum::<Result<u32, _>>()).unwrap(),
+            vec![3, 3]
+        );
+        assert_eq!(SolveError::new("no path").to_string(), "no path");
+    }
+}
@@ -0,0 +1,149 @@
+//! Debug output of solvers, which is silent unless the runner enables it.
+//!
+//! Messages are written with the [debug](crate::debug) and [trace](crate::trace::trace) macros. The runner
+//! enables them with a verbosity (debug messages from 1, trace messages from 2) or for specific scopes,
+//! like 2024/21, which show every message of this solver. Messages go to stderr or a file,
+//! so they never mix with the results on stdout.
+
+use std::cell::RefCell;
+use std::fmt::Arguments;
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Mutex, OnceLock};
+
+static CONFIG: OnceLock<TraceConfig> = OnceLock::new();
+
+thread_local! {
+    /// The scope of the solver running on the current thread, like 2024/21.
+    static SCOPE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The level of a message. A higher level means more detailed output.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Debug = 1,
+    Trace = 2,
+}
+
+/// Which messages are written and where to.
+pub struct TraceConfig {
+    /// Messages up to this level are written for every scope. 0 writes no messages.
+    pub verbosity: u8,
+    /// Every message in these scopes is written, independent of the verbosity.
+    pub scopes: Vec<String>,
+    /// The file the messages are written to. Messages are written to stderr if no file is set.
+    pub file: Option<Mutex<File>>,
+}
+
+/// Set the configuration. It can only be set once, later calls are ignored.
+pub fn init(config: TraceConfig) {
+    let _ = CONFIG.set(config);
+}
+
+/// Execute the given function with the given scope as the scope of all messages written on the current thread.
+///
+/// The scope is not passed on to other threads, like the rayon workers a solver uses, so their messages have
+/// no scope. They are only written if the verbosity allows it, not if only the scope of their solver is traced.
+pub fn with_scope<T>(
+    scope: &str,
+    f: impl FnOnce() -> T,
+) -> T {
+    let previous = SCOPE.replace(Some(scope.to_string()));
+    let result = f();
+    SCOPE.set(previous);
+    result
+}
+
+/// Tells if messages of the given level are written in the current scope.
+pub fn enabled(level: Level) -> bool {
+    let Some(config) = CONFIG.get() else {
+        return false;
+    };
+
+    level as u8 <= config.verbosity
+        || SCOPE.with_borrow(|scope| scope.as_ref().is_some_and(|scope| config.scopes.contains(scope)))
+}
+
+/// Write the given message, prefixed with the current scope and the level. Use the macros instead,
+/// as they do not format the message if it is not written.
+#[doc(hidden)]
+pub fn write(
+    level: Level,
+    message: Arguments,
+) {
+    let Some(config) = CONFIG.get() else {
+        return;
+    };
+
+    let level = match level {
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    };
+    let line = SCOPE.with_borrow(|scope| match scope {
+        Some(scope) => format!("[{scope} {level}] {message}"),
+        None => format!("[{level}] {message}"),
+    });
+
+    match &config.file {
+        Some(file) => {
+            let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+            // Tracing must never break a solver, so failed writes are ignored
+            let _ = writeln!(file, "{line}");
+        }
+        None => eprintln!("{line}"),
+    }
+}
+
+/// Write a debug message, which is shown with verbosity 1 (-v) or if its scope is traced.
+/// Takes the same arguments as [format].
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug) {
+            $crate::trace::write($crate::trace::Level::Debug, format_args!($($arg)*))
+        }
+    };
+}
+
+/// Write a trace message, which is shown with verbosity 2 (-vv) or if its scope is traced.
+/// Takes the same arguments as [format].
+// Exported under another name, as a macro named trace at the crate root could not be imported without this module
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace) {
+            $crate::trace::write($crate::trace::Level::Trace, format_args!($($arg)*))
+        }
+    };
+}
+
+pub use crate::__trace as trace;
+
+#[cfg(test)]
+mod tests {
+    use crate::trace::{init, with_scope, TraceConfig};
+    use std::fs::{read_to_string, File};
+    use std::sync::Mutex;
+
+    #[test]
+    fn only_traced_scopes_are_written() {
+        let path = std::env::temp_dir().join(format!("helpers_trace_test_{}", std::process::id()));
+        init(TraceConfig {
+            verbosity: 0,
+            scopes: vec!["2024/21".to_string()],
+            file: Some(Mutex::new(File::create(&path).unwrap())),
+        });
+
+        crate::debug!("not traced");
+        with_scope("2024/20", || crate::debug!("not traced either"));
+        with_scope("2024/21", || {
+            crate::debug!("depth {}", 3);
+            crate::trace::trace!("cached");
+        });
+
+        let output = read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(output, "[2024/21 debug] depth 3\n[2024/21 trace] cached\n");
+    }
+}
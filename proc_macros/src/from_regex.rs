@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, Attribute, Fields, FieldsNamed, FieldsUnnamed, Item, ItemEnum, ItemStruct, LitStr, Variant};
+use syn::{parse_macro_input, Attribute, Fields, FieldsNamed, FieldsUnnamed, Item, ItemEnum, ItemStruct, LitStr, Type, Variant};
 
 pub(crate) fn create(input: TokenStream) -> TokenStream {
     let item = parse_macro_input!(input as Item);
@@ -59,27 +59,120 @@ fn create_from_regex_struct_implementation(item_struct: &ItemStruct) -> proc_mac
         "{}_REGEX", item_struct.ident.to_string().to_uppercase()
     );
 
-    let if_statement = match &item_struct.fields {
-        Fields::Named(f) => create_named_struct_if(struct_ident, &regex_ident, f),
-        Fields::Unnamed(f) => create_unnamed_struct_if(struct_ident, &regex_ident, f),
-        Fields::Unit => create_unit_struct_if(struct_ident, &regex_ident)
+    let if_statement = |fallible| match &item_struct.fields {
+        Fields::Named(f) => create_named_struct_if(struct_ident, &regex_ident, f, fallible),
+        Fields::Unnamed(f) => create_unnamed_struct_if(struct_ident, &regex_ident, f, fallible),
+        Fields::Unit => create_unit_struct_if(struct_ident, &regex_ident, fallible)
     };
 
+    create_from_regex_methods(
+        quote! { impl #generics #struct_ident #generics #where_clause },
+        (if_statement(false), if_statement(true)),
+        item_struct.fields.iter().map(|f| &f.ty),
+        (
+            quote! { "The regex did not match the haystack '{haystack}!'" },
+            quote! { "The regex did not match the haystack '{haystack}'" },
+        ),
+    )
+}
+
+/// Create from_regex, which panics if the haystack cannot be parsed, and try_from_regex, which returns
+/// an error instead. The ifs of both return the parsed value if their regex matches.
+///
+/// The error of parsing a field becomes the source of the error of try_from_regex, so it requires the errors of
+/// every field type to implement Error. This is only a bound of try_from_regex, so from_regex still works with
+/// any error which implements Debug. The bounds are higher-ranked, as the compiler would reject a method with a
+/// bound which can never hold otherwise, like of a field whose error is a String.
+fn create_from_regex_methods<'a>(
+    impl_header: proc_macro2::TokenStream,
+    (ifs, fallible_ifs): (proc_macro2::TokenStream, proc_macro2::TokenStream),
+    field_types: impl Iterator<Item=&'a Type>,
+    (no_match, fallible_no_match): (proc_macro2::TokenStream, proc_macro2::TokenStream),
+) -> proc_macro2::TokenStream {
+    let bounds = field_types.map(|ty| quote! {
+        for<'e> <#ty as ::std::str::FromStr>::Err: ::std::error::Error + ::std::marker::Send + ::std::marker::Sync + 'static
+    });
+
     quote! {
-        impl #generics #struct_ident #generics #where_clause {
+        #impl_header {
+            #[allow(dead_code)]
             fn from_regex(haystack: &str) -> Self {
-                #if_statement
+                #ifs
 
-                panic!("The regex did not match the haystack '{haystack}!'")
+                panic!(#no_match)
+            }
+
+            #[allow(dead_code)]
+            fn try_from_regex(haystack: &str) -> ::std::result::Result<Self, ::helpers::prelude::SolveError>
+            where #(#bounds),*
+            {
+                #fallible_ifs
+
+                ::std::result::Result::Err(::helpers::prelude::SolveError::new(format!(#fallible_no_match)).with_snippet(haystack))
             }
         }
     }
 }
 
+/// Get the capture with the given index and parse it to the given type. Panics if the capture does not exist or
+/// cannot be parsed, unless it is fallible, see [parse_capture].
+fn capture_value(
+    i: usize,
+    ty: &Type,
+    fallible: bool,
+) -> proc_macro2::TokenStream {
+    if fallible {
+        return parse_capture(i, ty);
+    }
+
+    quote! { capture
+        .get(#i)
+        .expect(&format!("Expected {} capture in the haystack", #i))
+        .as_str()
+        .parse::<#ty>()
+        .expect(&format!("Failed to parse '{}' as {}", capture.get(#i).unwrap().as_str(), stringify!(#ty)))
+    }
+}
+
+/// The value an if returns, which is wrapped in Ok if it is fallible.
+fn returned(
+    value: proc_macro2::TokenStream,
+    fallible: bool,
+) -> proc_macro2::TokenStream {
+    if fallible {
+        quote! { ::std::result::Result::Ok(#value) }
+    } else {
+        value
+    }
+}
+
+/// Get the capture with the given index and parse it to the given type. Returns an error from the
+/// surrounding try_from_regex if the capture does not exist or cannot be parsed, located at the column of the capture
+/// and with the parse error as its source.
+fn parse_capture(
+    i: usize,
+    ty: &Type,
+) -> proc_macro2::TokenStream {
+    quote! {{
+        let value = capture.get(#i).ok_or_else(|| {
+            ::helpers::prelude::SolveError::new(format!("Expected {} capture in the haystack '{haystack}'", #i))
+                .with_snippet(haystack)
+        })?;
+
+        value.as_str().parse::<#ty>().map_err(|e| {
+            ::helpers::prelude::SolveError::new(format!("Failed to parse '{}' as {}: {e}", value.as_str(), stringify!(#ty)))
+                .with_snippet(value.as_str())
+                .at_column(haystack[..value.start()].chars().count() + 1)
+                .with_source(e)
+        })?
+    }}
+}
+
 fn create_named_struct_if(
     struct_ident: &Ident,
     static_ident: &Ident,
-    f: &FieldsNamed
+    f: &FieldsNamed,
+    fallible: bool,
 ) -> proc_macro2::TokenStream {
     let params = f.named
         .iter()
@@ -87,21 +180,15 @@ fn create_named_struct_if(
         .map(|(i, f)| {
             let i = i + 1;
             let ident = f.ident.as_ref().unwrap();
-            let ty = &f.ty;
-            quote! { #ident: capture
-                .get(#i)
-                .expect(&format!("Expected {} capture in the haystack", #i))
-                .as_str()
-                .parse::<#ty>()
-                .expect(&format!("Failed to parse '{}' as {}", capture.get(#i).unwrap().as_str(), stringify!(#ty)))
-            }
+            let value = capture_value(i, &f.ty, fallible);
+            quote! { #ident: #value }
         });
 
+    let value = returned(quote! { #struct_ident { #(#params),* } }, fallible);
+
     quote! {
         if let Some(capture) = #static_ident.captures(haystack) {
-            return #struct_ident {
-                #(#params),*
-            }
+            return #value
         }
     }
 }
@@ -109,26 +196,23 @@ fn create_named_struct_if(
 fn create_unnamed_struct_if(
     struct_ident: &Ident,
     static_ident: &Ident,
-    f: &FieldsUnnamed
+    f: &FieldsUnnamed,
+    fallible: bool,
 ) -> proc_macro2::TokenStream {
     let params = f.unnamed
         .iter()
         .enumerate()
         .map(|(i, f)| {
             let i = i + 1;
-            let ty = &f.ty;
-            quote! { capture
-                .get(#i)
-                .expect(&format!("Expected {} capture in the haystack", #i))
-                .as_str()
-                .parse::<#ty>()
-                .expect(&format!("Failed to parse '{}' as {}", capture.get(#i).unwrap().as_str(), stringify!(#ty)))
-            }
+            let value = capture_value(i, &f.ty, fallible);
+            quote! { #value }
         });
 
+    let value = returned(quote! { #struct_ident(#(#params),*) }, fallible);
+
     quote! {
         if let Some(capture) = #static_ident.captures(haystack) {
-            return #struct_ident(#(#params),*)
+            return #value
         }
     }
 }
@@ -136,10 +220,13 @@ fn create_unnamed_struct_if(
 fn create_unit_struct_if(
     struct_ident: &Ident,
     static_ident: &Ident,
+    fallible: bool,
 ) -> proc_macro2::TokenStream {
+    let value = returned(quote! { #struct_ident }, fallible);
+
     quote! {
         if #static_ident.is_match(haystack) {
-            return #struct_ident;
+            return #value;
         }
     }
 }
@@ -207,7 +294,7 @@ fn create_from_regex_enum_implementation(item_enum: &ItemEnum) -> proc_macro2::T
     let generics = &item_enum.generics;
     let where_clause = &generics.where_clause;
 
-    let ifs = item_enum.variants
+    let ifs = |fallible| item_enum.variants
         .iter()
         .map(|variant| {
             let static_ident = format_ident!(
@@ -216,28 +303,30 @@ fn create_from_regex_enum_implementation(item_enum: &ItemEnum) -> proc_macro2::T
             );
 
             match &variant.fields {
-                Fields::Named(f) => create_named_variant_if(enum_ident, &static_ident, variant, f),
-                Fields::Unnamed(f) => create_unnamed_variant_if(enum_ident, &static_ident, variant, f),
-                Fields::Unit => create_unit_variant_if(enum_ident, &static_ident, variant)
-            }
-        });
-
-    quote! {
-        impl #generics #enum_ident #generics #where_clause {
-            fn from_regex(haystack: &str) -> Self {
-                #(#ifs)*
-
-                panic!("None of the variant regexes matches the haystack '{haystack}!'")
+                Fields::Named(f) => create_named_variant_if(enum_ident, &static_ident, variant, f, fallible),
+                Fields::Unnamed(f) => create_unnamed_variant_if(enum_ident, &static_ident, variant, f, fallible),
+                Fields::Unit => create_unit_variant_if(enum_ident, &static_ident, variant, fallible)
             }
-        }
-    }
+        })
+        .collect::<proc_macro2::TokenStream>();
+
+    create_from_regex_methods(
+        quote! { impl #generics #enum_ident #generics #where_clause },
+        (ifs(false), ifs(true)),
+        item_enum.variants.iter().flat_map(|variant| variant.fields.iter().map(|f| &f.ty)),
+        (
+            quote! { "None of the variant regexes matches the haystack '{haystack}!'" },
+            quote! { "None of the variant regexes matches the haystack '{haystack}'" },
+        ),
+    )
 }
 
 fn create_named_variant_if(
     enum_ident: &Ident,
     static_ident: &Ident,
     variant: &Variant,
-    f: &FieldsNamed
+    f: &FieldsNamed,
+    fallible: bool,
 )  -> proc_macro2::TokenStream {
     let ident = &variant.ident;
     let params = f.named
@@ -246,21 +335,15 @@ fn create_named_variant_if(
         .map(|(i, f)| {
             let i = i + 1;
             let ident = f.ident.as_ref().unwrap();
-            let ty = &f.ty;
-            quote! { #ident: capture
-                .get(#i)
-                .expect(&format!("Expected {} capture in the haystack", #i))
-                .as_str()
-                .parse::<#ty>()
-                .expect(&format!("Failed to parse '{}' as {}", capture.get(#i).unwrap().as_str(), stringify!(#ty)))
-            }
+            let value = capture_value(i, &f.ty, fallible);
+            quote! { #ident: #value }
         });
 
+    let value = returned(quote! { #enum_ident::#ident { #(#params),* } }, fallible);
+
     quote! {
         if let Some(capture) = #static_ident.captures(haystack) {
-            return #enum_ident::#ident {
-                #(#params),*
-            }
+            return #value
         }
     }
 }
@@ -269,7 +352,8 @@ fn create_unnamed_variant_if(
     enum_ident: &Ident,
     static_ident: &Ident,
     variant: &Variant,
-    f: &FieldsUnnamed
+    f: &FieldsUnnamed,
+    fallible: bool,
 ) -> proc_macro2::TokenStream {
     let ident = &variant.ident;
     let params = f.unnamed
@@ -277,19 +361,15 @@ fn create_unnamed_variant_if(
         .enumerate()
         .map(|(i, f)| {
             let i = i + 1;
-            let ty = &f.ty;
-            quote! { capture
-                .get(#i)
-                .expect(&format!("Expected {} capture in the haystack", #i))
-                .as_str()
-                .parse::<#ty>()
-                .expect(&format!("Failed to parse '{}' as {}", capture.get(#i).unwrap().as_str(), stringify!(#ty)))
-            }
+            let value = capture_value(i, &f.ty, fallible);
+            quote! { #value }
         });
 
+    let value = returned(quote! { #enum_ident::#ident(#(#params),*) }, fallible);
+
     quote! {
         if let Some(capture) = #static_ident.captures(haystack) {
-            return #enum_ident::#ident(#(#params),*)
+            return #value
         }
     }
 }
@@ -297,12 +377,15 @@ fn create_unnamed_variant_if(
 fn create_unit_variant_if(
     enum_ident: &Ident,
     static_ident: &Ident,
-    variant: &Variant
+    variant: &Variant,
+    fallible: bool,
 ) -> proc_macro2::TokenStream {
     let ident = &variant.ident;
+    let value = returned(quote! { #enum_ident::#ident }, fallible);
+
     quote! {
         if #static_ident.is_match(haystack) {
-            return #enum_ident::#ident;
+            return #value;
         }
     }
 }
@@ -1,4 +1,5 @@
 mod from_regex;
+mod solver;
 mod tile;
 
 // todo cool idea: create a memoize macro which transforms a function into a memoized version (and maybe apply this macro on 2024 d11)
@@ -34,6 +35,14 @@ pub fn tile(_attributes: TokenStream, item: TokenStream) -> TokenStream {
 /// The method will panic if the regex is wrong (not enough captures or captures returning the wrong type)
 /// or if none of the regexes match the given haystack. Requires the [regex crate](https://docs.rs/regex/latest/regex/).
 ///
+/// Also generates try_from_regex, which returns a SolveError from the helpers crate instead of panicking.
+/// The error contains the column of a capture which cannot be parsed, so it can be located in the input.
+/// The error of parsing the capture is its source, so try_from_regex can only be called if the FromStr::Err of
+/// every field type implements Error + Send + Sync + 'static. from_regex only requires them to implement Debug.
+///
+/// The generated code refers to the error as `::helpers::prelude::SolveError`, so every crate which uses
+/// this attribute must depend on the [helpers] crate under the name helpers, besides the regex crate.
+///
 /// Usage on structs:
 /// ```
 ///
@@ -77,3 +86,63 @@ pub fn tile(_attributes: TokenStream, item: TokenStream) -> TokenStream {
 pub fn from_regex(_attr: TokenStream, input: TokenStream) -> TokenStream {
     from_regex::create(input)
 }
+
+/// Registers a function as the solver for one part of a puzzle. The function must take the puzzle
+/// input as &str and return the answer, which must implement Display. The answer is checked by
+/// comparing its rendered string with the expected result, so grids can be checked as well.
+/// A solver can also return a Result (recognized by its name) with an error implementing Error.
+/// The runner reports the error and all of its sources instead of the answer.
+/// It can take the PuzzleContext as a second argument, to read the parameters of the puzzle or
+/// to tell examples and the real puzzle apart.
+///
+/// The year, day and part (a or b) of the puzzle are specified as arguments. Every registered
+/// solver is found by the runner automatically, so no dispatch code has to be written by hand.
+/// An optional timeout in seconds overrides the default timeout of the runner for this solver.
+/// Requires the [inventory crate](https://docs.rs/inventory/latest/inventory/) and can only
+/// be used inside the aoc_solvers crate, as it refers to its registry.
+///
+/// Example:
+///
+/// ```ignore
+/// use proc_macros::solver;
+///
+/// #[solver(year = 2024, day = 5, part = a)]
+/// pub fn solve_a(input: &str) -> usize {
+///     input.lines().count()
+/// }
+///
+/// #[solver(year = 2024, day = 5, part = b, timeout = 300)]
+/// pub fn solve_b(input: &str, context: &PuzzleContext) -> usize {
+///     input.len() * context.params().get_or("factor", 2)
+/// }
+/// ```
+///
+/// On an implementation of the Solver trait of aoc_solvers, the attribute registers both parts without
+/// a part argument. The input is parsed once and shared by both parts, so parsing is measured separately:
+///
+/// ```ignore
+/// pub struct Day5;
+///
+/// #[solver(year = 2024, day = 5)]
+/// impl Solver for Day5 {
+///     type Parsed = Vec<usize>;
+///     type A = usize;
+///     type B = usize;
+///
+///     fn parse(input: &str) -> Vec<usize> {
+///         input.lines().map(|line| line.parse().unwrap()).collect()
+///     }
+///
+///     fn part_a(numbers: &Vec<usize>, _context: &PuzzleContext) -> usize {
+///         numbers.iter().sum()
+///     }
+///
+///     fn part_b(numbers: &Vec<usize>, _context: &PuzzleContext) -> usize {
+///         numbers.iter().product()
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn solver(attributes: TokenStream, item: TokenStream) -> TokenStream {
+    solver::create(attributes, item)
+}
@@ -0,0 +1,190 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Error, Ident, ImplItem, Item, ItemFn, ItemImpl, LitInt, ReturnType, Type};
+
+pub (crate) fn create(attributes: TokenStream, item: TokenStream) -> TokenStream {
+    let mut year: Option<LitInt> = None;
+    let mut day: Option<LitInt> = None;
+    let mut part: Option<Ident> = None;
+    let mut timeout: Option<LitInt> = None;
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("year") {
+            year = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("day") {
+            day = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("part") {
+            part = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("timeout") {
+            timeout = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("unsupported solver argument, expected year, day, part or timeout"));
+        }
+
+        Ok(())
+    });
+    parse_macro_input!(attributes with parser);
+
+    let item = parse_macro_input!(item as Item);
+
+    let (Some(year), Some(day)) = (year, day) else {
+        return Error::new(
+            Span::call_site(),
+            "the solver attribute requires a year and a day, like #[solver(year = 2024, day = 5, part = a)]",
+        ).to_compile_error().into();
+    };
+    let timeout = match timeout {
+        Some(seconds) => quote! { ::std::option::Option::Some(::std::time::Duration::from_secs(#seconds)) },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    match item {
+        Item::Fn(item_fn) => create_function(year, day, part, timeout, item_fn),
+        Item::Impl(item_impl) => create_impl(year, day, part, timeout, item_impl),
+        item => Error::new(item.span(), "the solver attribute can only be used on functions and impl blocks of Solver")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+/// Register the given function as the solver of the given part.
+fn create_function(
+    year: LitInt,
+    day: LitInt,
+    part: Option<Ident>,
+    timeout: proc_macro2::TokenStream,
+    item_fn: ItemFn,
+) -> TokenStream {
+    let Some(part) = part else {
+        return Error::new(
+            item_fn.sig.ident.span(),
+            "a solver function requires a part, like #[solver(year = 2024, day = 5, part = a)]",
+        ).to_compile_error().into();
+    };
+
+    let part_variant = match part.to_string().as_str() {
+        "a" => format_ident!("A"),
+        "b" => format_ident!("B"),
+        _ => return Error::new(part.span(), "the part must be either a or b").to_compile_error().into()
+    };
+
+    let ReturnType::Type(_, output) = &item_fn.sig.output else {
+        return Error::new(
+            item_fn.sig.ident.span(),
+            "a solver must return the answer of the puzzle",
+        ).to_compile_error().into()
+    };
+    let output = type_name(output);
+
+    let ident = &item_fn.sig.ident;
+    let (arguments, call) = match item_fn.sig.inputs.len() {
+        1 => (quote! { |input, _| }, quote! { #ident(input) }),
+        2 => (quote! { |input, context| }, quote! { #ident(input, context) }),
+        _ => return Error::new(
+            item_fn.sig.inputs.span(),
+            "a solver must take the puzzle input and optionally the puzzle context, like (input: &str, context: &PuzzleContext)",
+        ).to_compile_error().into()
+    };
+    // The return type is checked by its name, as the macro cannot resolve types
+    let solver = if returns_result(&item_fn.sig.output) {
+        quote! { #arguments crate::registry::fallible(#call) }
+    } else {
+        quote! { #arguments ::std::boxed::Box::new(#call) }
+    };
+
+    quote! {
+        #item_fn
+
+        ::inventory::submit! {
+            crate::registry::SolverEntry {
+                year: #year,
+                day: #day,
+                part: crate::registry::Part::#part_variant,
+                solver: crate::registry::SolverKind::Function(#solver),
+                output: #output,
+                timeout: #timeout,
+            }
+        }
+    }.into()
+}
+
+/// Register both parts of the given implementation of Solver. They share the parser of the implementation.
+fn create_impl(
+    year: LitInt,
+    day: LitInt,
+    part: Option<Ident>,
+    timeout: proc_macro2::TokenStream,
+    item_impl: ItemImpl,
+) -> TokenStream {
+    if let Some(part) = part {
+        return Error::new(part.span(), "a Solver implements both parts, so it does not take a part")
+            .to_compile_error()
+            .into();
+    }
+
+    if item_impl.trait_.is_none() {
+        return Error::new(item_impl.self_ty.span(), "the solver attribute requires an implementation of Solver")
+            .to_compile_error()
+            .into();
+    }
+
+    let self_ty = &item_impl.self_ty;
+    // The types of the answers are taken from the associated types A and B
+    let output = |name: &str| {
+        item_impl.items.iter().find_map(|item| match item {
+            ImplItem::Type(ty) if ty.ident == name => Some(type_name(&ty.ty)),
+            _ => None,
+        })
+    };
+    let (Some(output_a), Some(output_b)) = (output("A"), output("B")) else {
+        return Error::new(item_impl.self_ty.span(), "a Solver must set the types A and B of its answers")
+            .to_compile_error()
+            .into();
+    };
+    let entries = [
+        (format_ident!("A"), format_ident!("part_a"), output_a),
+        (format_ident!("B"), format_ident!("part_b"), output_b),
+    ]
+        .map(|(part_variant, solve, output)| quote! {
+            ::inventory::submit! {
+                crate::registry::SolverEntry {
+                    year: #year,
+                    day: #day,
+                    part: crate::registry::Part::#part_variant,
+                    solver: crate::registry::SolverKind::Parsed {
+                        parser: crate::registry::parse::<#self_ty>,
+                        solver: crate::registry::#solve::<#self_ty>,
+                    },
+                    output: #output,
+                    timeout: #timeout,
+                }
+            }
+        });
+
+    quote! {
+        #item_impl
+
+        #(#entries)*
+    }.into()
+}
+
+/// The name of the given type as it is written, like Result<usize, SolveError>. The tokens of the type are
+/// separated by spaces, which are removed around the punctuation.
+fn type_name(ty: &Type) -> String {
+    [(" <", "<"), ("< ", "<"), (" >", ">"), (" ,", ","), (" :: ", "::"), (":: ", "::"), ("& ", "&"), ("( ", "("), (" )", ")")]
+        .into_iter()
+        .fold(quote! { #ty }.to_string(), |name, (spaced, tight)| name.replace(spaced, tight))
+}
+
+/// Tells if the given return type is a Result, like Result<usize, SolveError>.
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => path.path.segments.last().is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
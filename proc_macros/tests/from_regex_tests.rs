@@ -140,4 +140,50 @@ fn enum_generics() {
         Enum::<usize, String>::C,
         Enum::<usize, String>::from_regex("C")
     )
-}
\ No newline at end of file
+}
+
+#[test]
+fn try_from_regex_returns_located_errors() {
+    use std::error::Error;
+
+    #[from_regex]
+    #[derive(Debug, Eq, PartialEq)]
+    enum Instruction {
+        #[reg(r#"addx (-?\w+)"#)]
+        Add(isize),
+        #[reg(r#"noop"#)]
+        Noop
+    }
+
+    assert_eq!(Instruction::Add(-3), Instruction::try_from_regex("addx -3").unwrap());
+    assert_eq!(Instruction::Noop, Instruction::try_from_regex("noop").unwrap());
+
+    let error = Instruction::try_from_regex("addx 3x").unwrap_err();
+    assert_eq!(error.column(), Some(6));
+    assert_eq!(error.to_string(), "column 6: Failed to parse '3x' as isize: invalid digit found in string");
+    assert!(error.source().is_some());
+
+    let error = Instruction::try_from_regex("jump 3").unwrap_err();
+    assert_eq!(error.to_string(), "None of the variant regexes matches the haystack 'jump 3'");
+}
+
+#[test]
+fn from_regex_works_with_errors_which_are_no_error_type() {
+    #[derive(Debug, Eq, PartialEq)]
+    struct Name(String);
+
+    impl FromStr for Name {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Name(s.to_string()))
+        }
+    }
+
+    #[from_regex]
+    #[reg(r#"hello (\w+)"#)]
+    #[derive(Debug, Eq, PartialEq)]
+    struct Greeting(Name);
+
+    assert_eq!(Greeting(Name("world".to_string())), Greeting::from_regex("hello world"));
+}
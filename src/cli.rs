@@ -0,0 +1,272 @@
+use aoc_solvers::{Part, RunConfig, Verdict};
+use clap::{ArgAction, Args, Parser, Subcommand};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Run, list and create advent of code puzzle solvers.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+    /// The directory which contains the input directory <year>/<day>/ of every puzzle.
+    /// Defaults to input_root in aoc.toml or the input directory of the workspace
+    #[arg(long, global = true, env = "AOC_INPUT", value_name = "DIR")]
+    pub input: Option<PathBuf>,
+    /// Show the debug messages of the solvers (-v) or also their trace messages (-vv)
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+    /// Show every message of the given solver, like 2024/21. Can be repeated
+    #[arg(long, global = true, value_name = "YEAR/DAY", value_parser = parse_scope)]
+    pub trace: Vec<String>,
+    /// Write the messages of the solvers to the given file instead of stderr
+    #[arg(long, global = true, value_name = "FILE")]
+    pub trace_file: Option<PathBuf>,
+}
+
+/// Parse a trace scope like 2024/21. Leading zeros are removed, so it matches the scope of the solver.
+fn parse_scope(s: &str) -> Result<String, String> {
+    let (year, day) = s
+        .split_once('/')
+        .ok_or_else(|| format!("'{s}' is not a valid scope, expected <year>/<day>, like 2024/21"))?;
+    let year = year
+        .parse::<u16>()
+        .map_err(|_| format!("'{year}' is not a valid year"))?;
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| format!("'{day}' is not a valid day"))?;
+
+    Ok(format!("{year}/{day}"))
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run the examples and puzzles of the given years and days
+    Run {
+        /// The year(s) of the puzzle, like 2024 or 2022..=2024
+        years: Span<u16>,
+        /// The day(s) of the puzzle, like 5, 1..10 or 1..=10
+        days: Span<u8>,
+        #[command(flatten)]
+        parts: PartFlags,
+        #[command(flatten)]
+        reports: ReportArgs,
+    },
+    /// Run the examples and puzzles of every existing solver
+    All {
+        #[command(flatten)]
+        parts: PartFlags,
+        #[command(flatten)]
+        reports: ReportArgs,
+    },
+    /// List every year and day a solver exists for
+    List {
+        /// Only list the given year(s), like 2024 or 2022..=2024
+        years: Option<Span<u16>>,
+    },
+    /// Run a puzzle repeatedly and report statistics about how long it took
+    Bench {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        day: u8,
+        #[command(flatten)]
+        parts: PartFlags,
+        /// How often every selected example and puzzle is executed, at least 2
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+        #[command(flatten)]
+        history: HistoryArgs,
+    },
+    /// Print the recorded benchmark results of a day for every commit, to see how its performance changed
+    Trend {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        day: u8,
+        /// The file with the recorded benchmark results. Defaults to bench_history in the workspace root
+        #[arg(long, value_name = "FILE")]
+        history: Option<PathBuf>,
+    },
+    /// Create the solver module and input directory for a new day
+    New {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        day: u8,
+    },
+    /// Record an answer which was submitted on the website in the answer log of the day.
+    /// Later runs warn if a solver returns an answer which is known to be wrong
+    Answer {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        day: u8,
+        /// The part of the puzzle, a or b
+        part: Part,
+        /// The submitted answer
+        answer: String,
+        /// What the website said about the answer: correct, wrong, too_high or too_low
+        verdict: Verdict,
+    },
+}
+
+/// Selects which examples and puzzles are executed and how long they may run.
+/// If no part flag is set, everything is executed.
+#[derive(Args)]
+pub struct PartFlags {
+    /// Run example A
+    #[arg(long)]
+    pub ea: bool,
+    /// Run example B
+    #[arg(long)]
+    pub eb: bool,
+    /// Run puzzle A
+    #[arg(short, long)]
+    pub a: bool,
+    /// Run puzzle B
+    #[arg(short, long)]
+    pub b: bool,
+    /// Abandon a solver after the given number of seconds, unless the solver sets its own timeout. 0 disables all timeouts
+    #[arg(long, value_name = "SECONDS", default_value_t = 60)]
+    pub timeout: u64,
+}
+
+impl PartFlags {
+    pub fn run_config(
+        &self,
+        input_root: PathBuf,
+    ) -> RunConfig {
+        let timeout = (self.timeout > 0).then(|| Duration::from_secs(self.timeout));
+
+        if !(self.ea || self.eb || self.a || self.b) {
+            return RunConfig {
+                timeout,
+                input_root,
+                ..RunConfig::default()
+            };
+        }
+
+        RunConfig {
+            run_example_a: self.ea,
+            run_a: self.a,
+            run_example_b: self.eb,
+            run_b: self.b,
+            timeout,
+            input_root,
+            ..RunConfig::default()
+        }
+    }
+}
+
+/// Where the benchmark results are recorded and whether they are compared with earlier results.
+#[derive(Args)]
+pub struct HistoryArgs {
+    /// Compare the results of the puzzles with the latest results of another commit and fail if they got slower
+    #[arg(long)]
+    pub compare: bool,
+    /// How many percent slower than the earlier result a puzzle may get before it is reported as a regression
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    pub threshold: f64,
+    /// The file the benchmark results are recorded in. Defaults to bench_history in the workspace root
+    #[arg(long, value_name = "FILE")]
+    pub history: Option<PathBuf>,
+}
+
+/// Machine-readable reports which are written after all puzzles were executed.
+#[derive(Args)]
+pub struct ReportArgs {
+    /// Write the results as JSON to the given file
+    #[arg(long, value_name = "FILE")]
+    pub json: Option<PathBuf>,
+    /// Write the results as JUnit XML to the given file
+    #[arg(long, value_name = "FILE")]
+    pub junit: Option<PathBuf>,
+}
+
+/// An inclusive range of years or days. Can be parsed from a single value (`5`),
+/// an exclusive range (`1..10`) or an inclusive range (`1..=10`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Span<T>(RangeInclusive<T>);
+
+impl<T: Copy> Span<T> {
+    pub fn range(&self) -> RangeInclusive<T> {
+        self.0.clone()
+    }
+
+    /// Tells if this span contains only a single value.
+    pub fn is_single(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.start() == self.0.end()
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for Span<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Span(range)
+    }
+}
+
+impl<T> FromStr for Span<T>
+where
+    T: Copy + FromStr + PartialOrd + TryFrom<u32>,
+    u32: From<T>,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_value = |value: &str| {
+            value
+                .trim()
+                .parse::<T>()
+                .map_err(|_| format!("'{value}' is not a valid number"))
+        };
+
+        let (start, end) = if let Some((start, end)) = s.split_once("..=") {
+            (parse_value(start)?, parse_value(end)?)
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = parse_value(end)?;
+            let end = u32::from(end)
+                .checked_sub(1)
+                .and_then(|e| T::try_from(e).ok())
+                .ok_or_else(|| format!("'{s}' is an empty range"))?;
+            (parse_value(start)?, end)
+        } else {
+            let value = parse_value(s)?;
+            (value, value)
+        };
+
+        if start > end {
+            return Err(format!("'{s}' is an empty range"));
+        }
+
+        Ok(Span(start..=end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::Span;
+
+    #[test]
+    fn span_parsing_works() {
+        assert_eq!("5".parse::<Span<u8>>(), Ok(Span(5..=5)));
+        assert_eq!("1..10".parse::<Span<u8>>(), Ok(Span(1..=9)));
+        assert_eq!("1..=10".parse::<Span<u8>>(), Ok(Span(1..=10)));
+        assert_eq!("2022..=2024".parse::<Span<u16>>(), Ok(Span(2022..=2024)));
+    }
+
+    #[test]
+    fn invalid_spans_are_rejected() {
+        assert!("".parse::<Span<u8>>().is_err());
+        assert!("a".parse::<Span<u8>>().is_err());
+        assert!("1..1".parse::<Span<u8>>().is_err());
+        assert!("10..=1".parse::<Span<u8>>().is_err());
+        assert!("0..0".parse::<Span<u8>>().is_err());
+        assert!("300".parse::<Span<u8>>().is_err());
+    }
+}
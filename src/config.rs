@@ -0,0 +1,62 @@
+use serde::Deserialize;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The optional config file in the workspace root.
+const CONFIG_FILE: &str = "aoc.toml";
+
+/// The settings of the config file. Every setting is optional.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    /// The directory which contains the input directories of every puzzle. Relative to the workspace root.
+    input_root: Option<PathBuf>,
+}
+
+/// Resolve the directory which contains the input directories of every puzzle.
+///
+/// The given root (set by --input or the AOC_INPUT environment variable) takes precedence over
+/// the input_root of the config file, which takes precedence over the input directory of the workspace.
+/// The default is resolved relative to the workspace, so it does not depend on the current directory.
+pub fn input_root(given: Option<&Path>) -> Result<PathBuf, String> {
+    if let Some(root) = given {
+        return Ok(root.to_path_buf());
+    }
+
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = load_config(&workspace.join(CONFIG_FILE))?;
+
+    Ok(workspace.join(config.input_root.unwrap_or_else(|| PathBuf::from("input"))))
+}
+
+/// Same as [input_root], but fails if the input root does not exist.
+pub fn existing_input_root(given: Option<&Path>) -> Result<PathBuf, String> {
+    let root = input_root(given)?;
+
+    if !root.is_dir() {
+        return Err(format!(
+            "The input root {} does not exist. Set it with --input, the AOC_INPUT environment variable or input_root in {CONFIG_FILE}",
+            root.display()
+        ));
+    }
+
+    Ok(root)
+}
+
+/// Resolve the file the benchmark results are recorded in. The given file (set by --history) takes precedence
+/// over the file bench_history in the workspace root.
+pub fn bench_history(given: Option<&Path>) -> PathBuf {
+    given
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("bench_history"))
+}
+
+/// Load the config file at the given path. A missing file is the same as an empty one.
+fn load_config(path: &Path) -> Result<Config, String> {
+    match read_to_string(path) {
+        Ok(content) => toml::from_str(&content).map_err(|e| format!("Failed to parse {}: {e}", path.display())),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Config::default()),
+        Err(e) => Err(format!("Failed to read {}: {e}", path.display())),
+    }
+}
@@ -0,0 +1,211 @@
+use aoc_solvers::normalize;
+use colored::{ColoredString, Colorize};
+
+/// Print what is different between the expected result and the result of a solver, below the line which
+/// reports the failure. Numbers show how far off they are, grids are shown side by side with the differing
+/// cells highlighted, other answers with multiple lines get a line diff and single lines a character diff.
+pub fn print_mismatch(
+    expected: &str,
+    was: &str,
+) {
+    for line in mismatch(expected, was) {
+        println!("    {line}");
+    }
+}
+
+fn mismatch(
+    expected: &str,
+    was: &str,
+) -> Vec<String> {
+    let expected = normalize(expected);
+    let was = normalize(was);
+
+    if let Some(delta) = numeric_delta(&expected, &was) {
+        return vec![delta];
+    }
+
+    if expected.contains('\n') || was.contains('\n') {
+        let expected_lines = expected.lines().collect::<Vec<_>>();
+        let was_lines = was.lines().collect::<Vec<_>>();
+
+        return if is_grid(&expected_lines) && is_grid(&was_lines) && expected_lines.len() == was_lines.len() {
+            grid_diff(&expected_lines, &was_lines)
+        } else {
+            line_diff(&expected_lines, &was_lines)
+        };
+    }
+
+    character_diff(&expected, &was)
+}
+
+/// Describe how far the result is off, if both are integers. Returns None if the difference does not fit into
+/// an i128 or if both are the same number written differently, like 007 and 7.
+fn numeric_delta(
+    expected: &str,
+    was: &str,
+) -> Option<String> {
+    let expected = expected.parse::<i128>().ok()?;
+    let was = was.parse::<i128>().ok()?;
+    let delta = was.checked_sub(expected).filter(|delta| *delta != 0)?;
+    let direction = if delta > 0 { "too high" } else { "too low" };
+
+    Some(format!("The result is {} {direction} ({delta:+})", delta.unsigned_abs()))
+}
+
+/// Show both grids side by side. Cells which differ are highlighted in both of them.
+fn grid_diff(
+    expected: &[&str],
+    was: &[&str],
+) -> Vec<String> {
+    let width = expected.first().map_or(0, |line| line.chars().count());
+    let differences = expected
+        .iter()
+        .zip(was)
+        .map(|(expected, was)| {
+            expected
+                .chars()
+                .zip(was.chars())
+                .filter(|(expected, was)| expected != was)
+                .count()
+        })
+        .sum::<usize>();
+
+    let header = format!("{:width$}   {}", "expected", "was", width = width.max(8));
+    let rows = expected.iter().zip(was).map(|(expected, was)| {
+        let cells = |line: &str, other: &str, highlight: fn(String) -> ColoredString| {
+            line.chars()
+                .zip(other.chars().map(Some).chain(std::iter::repeat(None)))
+                .map(|(cell, other)| match other {
+                    Some(other) if other == cell => cell.to_string(),
+                    _ => highlight(cell.to_string()).to_string(),
+                })
+                .collect::<String>()
+        };
+        // The header is wider than grids which are smaller than the word expected
+        let padding = " ".repeat(width.max(8) - width);
+
+        format!(
+            "{}{padding}   {}",
+            cells(expected, was, |cell| cell.green().bold()),
+            cells(was, expected, |cell| cell.red().bold())
+        )
+    });
+
+    [format!("{differences} cells differ:"), header.dimmed().to_string()]
+        .into_iter()
+        .chain(rows)
+        .collect()
+}
+
+/// Show every line which differs, with its number, the expected line and the line of the result.
+fn line_diff(
+    expected: &[&str],
+    was: &[&str],
+) -> Vec<String> {
+    let lines = expected.len().max(was.len());
+    let mut diff = Vec::new();
+
+    if expected.len() != was.len() {
+        diff.push(format!("Expected {} lines, but the result has {}", expected.len(), was.len()));
+    }
+
+    for line in 0..lines {
+        let expected_line = expected.get(line).copied();
+        let was_line = was.get(line).copied();
+
+        if expected_line == was_line {
+            continue;
+        }
+
+        diff.push(format!("line {}:", line + 1));
+
+        if let Some(expected_line) = expected_line {
+            diff.push(format!("- {expected_line}").green().to_string());
+        }
+
+        if let Some(was_line) = was_line {
+            diff.push(format!("+ {was_line}").red().to_string());
+        }
+    }
+
+    diff
+}
+
+/// Show both lines with the part highlighted which differs, between their common start and end.
+fn character_diff(
+    expected: &str,
+    was: &str,
+) -> Vec<String> {
+    let expected = expected.chars().collect::<Vec<_>>();
+    let was = was.chars().collect::<Vec<_>>();
+    let (prefix, suffix) = common_affixes(&expected, &was);
+
+    let highlight = |chars: &[char], style: fn(String) -> ColoredString| {
+        let end = chars.len() - suffix;
+        format!(
+            "{}{}{}",
+            chars[..prefix].iter().collect::<String>(),
+            style(chars[prefix..end].iter().collect()),
+            chars[end..].iter().collect::<String>()
+        )
+    };
+
+    vec![
+        format!("The results differ from character {} on:", prefix + 1),
+        format!("expected: {}", highlight(&expected, |part| part.green().bold())),
+        format!("was:      {}", highlight(&was, |part| part.red().bold())),
+    ]
+}
+
+/// The lengths of the common start and the common end of both texts, which do not overlap.
+fn common_affixes(
+    expected: &[char],
+    was: &[char],
+) -> (usize, usize) {
+    let prefix = expected
+        .iter()
+        .zip(was)
+        .take_while(|(expected, was)| expected == was)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(was[prefix..].iter().rev())
+        .take_while(|(expected, was)| expected == was)
+        .count();
+
+    (prefix, suffix)
+}
+
+/// Tells if the given lines form a grid, which are at least two lines of the same length.
+fn is_grid(lines: &[&str]) -> bool {
+    lines.len() > 1 && lines.iter().map(|line| line.chars().count()).all(|width| width == lines[0].chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diff::{common_affixes, mismatch, numeric_delta};
+
+    #[test]
+    fn numbers_show_how_far_they_are_off() {
+        assert_eq!(numeric_delta("100", "142"), Some("The result is 42 too high (+42)".to_string()));
+        assert_eq!(numeric_delta("100", "-1"), Some("The result is 101 too low (-101)".to_string()));
+        assert_eq!(numeric_delta("100", "1,0,0"), None);
+        assert_eq!(numeric_delta("7", "007"), None);
+        assert_eq!(numeric_delta("5", "+5"), None);
+        assert_eq!(numeric_delta(&i128::MIN.to_string(), &i128::MAX.to_string()), None);
+        assert_eq!(numeric_delta("-2", &i128::MAX.to_string()), None);
+        assert_eq!(mismatch("007", "7").len(), 3);
+    }
+
+    #[test]
+    fn only_the_differing_part_is_highlighted() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+
+        assert_eq!(common_affixes(&chars("4,6,3,5,6"), &chars("4,6,0,5,6")), (4, 4));
+        assert_eq!(common_affixes(&chars("co,de,ka"), &chars("co,de,ka,ta")), (8, 0));
+        assert_eq!(common_affixes(&chars("aaa"), &chars("aa")), (2, 0));
+        assert_eq!(mismatch("ab\ncd", "ab\ncx").len(), 4);
+        assert_eq!(mismatch("ab\ncd", "ab").len(), 3);
+    }
+}
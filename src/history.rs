@@ -0,0 +1,290 @@
+use aoc_solvers::{BenchStats, Part};
+use colored::Colorize;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// The benchmark result of one part of a puzzle, as recorded in the history file. The file contains one
+/// record per line, like `2024 20 a 3f2c1d0 9e107d9d372bb682 10 1200000 1300000 1400000 100000`, which are the
+/// year, day, part, commit, hash of the puzzle input, runs and the min, median, mean and standard deviation
+/// in nanoseconds. Lines starting with # are ignored.
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The commit the solver was built from. Ends with -dirty if there were uncommitted changes
+    pub commit: String,
+    /// The hash of the puzzle input, as results of different inputs cannot be compared
+    pub input_hash: String,
+    pub stats: BenchStats,
+}
+
+impl Record {
+    fn parse(line: &str) -> Result<Self, String> {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [year, day, part, commit, input_hash, runs, min, median, mean, std_dev] = fields[..] else {
+            return Err(format!("expected 10 fields, but there are {}", fields.len()));
+        };
+        let nanos = |value: &str| {
+            value
+                .parse::<u64>()
+                .map(Duration::from_nanos)
+                .map_err(|_| format!("'{value}' is not a valid number of nanoseconds"))
+        };
+
+        Ok(Record {
+            year: year.parse().map_err(|_| format!("'{year}' is not a valid year"))?,
+            day: day.parse().map_err(|_| format!("'{day}' is not a valid day"))?,
+            part: part.parse()?,
+            commit: commit.to_string(),
+            input_hash: input_hash.to_string(),
+            stats: BenchStats {
+                runs: runs.parse().map_err(|_| format!("'{runs}' is not a valid number of runs"))?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                mean: nanos(mean)?,
+                std_dev: nanos(std_dev)?,
+            },
+        })
+    }
+
+    fn to_line(&self) -> String {
+        let stats = &self.stats;
+
+        format!(
+            "{} {} {} {} {} {} {} {} {} {}",
+            self.year,
+            self.day,
+            part_name(self.part),
+            self.commit,
+            self.input_hash,
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.std_dev.as_nanos()
+        )
+    }
+
+    /// Tells if this is a result of the given part with the given input.
+    fn is_result_of(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        input_hash: &str,
+    ) -> bool {
+        self.year == year && self.day == day && self.part == part && self.input_hash == input_hash
+    }
+}
+
+/// Load every record of the history file at the given path. A missing file is the same as an empty one.
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let content = match read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+    };
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| Record::parse(line).map_err(|e| format!("Invalid line '{line}' in {}: {e}", path.display())))
+        .collect()
+}
+
+/// Append the given records to the history file at the given path, which is created if it does not exist.
+pub fn append(
+    path: &Path,
+    records: &[Record],
+) -> Result<(), String> {
+    let lines = records.iter().map(|record| record.to_line() + "\n").collect::<String>();
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+/// The latest record of the given part with the same input, which was created from another commit.
+pub fn baseline<'a>(
+    records: &'a [Record],
+    record: &Record,
+) -> Option<&'a Record> {
+    records
+        .iter()
+        .rev()
+        .filter(|baseline| baseline.is_result_of(record.year, record.day, record.part, &record.input_hash))
+        .find(|baseline| baseline.commit != record.commit)
+}
+
+/// How many percent the median of the given record is slower than the median of its baseline.
+/// Negative if it got faster.
+pub fn slowdown(
+    record: &Record,
+    baseline: &Record,
+) -> f64 {
+    let baseline = baseline.stats.median.as_nanos() as f64;
+
+    if baseline == 0.0 {
+        return 0.0;
+    }
+
+    (record.stats.median.as_nanos() as f64 - baseline) / baseline * 100.0
+}
+
+/// Print the median of both parts of the given day for every recorded commit, in the order they were
+/// recorded. Only results of the given input are shown, as results of different inputs cannot be compared.
+pub fn print_trend(
+    records: &[Record],
+    year: u16,
+    day: u8,
+    input_hash: &str,
+) {
+    let mut commits = Vec::<&str>::new();
+
+    for record in records.iter().filter(|record| record.year == year && record.day == day) {
+        if record.input_hash == input_hash && !commits.contains(&record.commit.as_str()) {
+            commits.push(&record.commit);
+        }
+    }
+
+    if commits.is_empty() {
+        println!("No benchmark results are recorded for {year} day {day} with the current input");
+        return;
+    }
+
+    println!("{:<16}{:>24}{:>24}", "Commit", "Median A", "Median B");
+    let mut previous = [None, None];
+
+    for commit in commits {
+        let cells = [Part::A, Part::B].map(|part| {
+            records
+                .iter()
+                .rev()
+                .find(|record| record.is_result_of(year, day, part, input_hash) && record.commit == commit)
+        });
+        let cells = [0, 1].map(|i| {
+            let Some(record) = cells[i] else {
+                return format!("{:>24}", "-").dimmed();
+            };
+            let change = previous[i].map(|previous| slowdown(record, previous));
+            previous[i] = Some(record);
+            let cell = match change {
+                Some(change) => format!("{:>24}", format!("{:.2?} ({change:+.1}%)", record.stats.median)),
+                None => format!("{:>24}", format!("{:.2?}", record.stats.median)),
+            };
+
+            match change {
+                Some(change) if change > 0.0 => cell.yellow(),
+                Some(_) => cell.green(),
+                None => cell.normal(),
+            }
+        });
+
+        println!("{commit:<16}{}{}", cells[0], cells[1]);
+    }
+}
+
+/// The hash of the given puzzle input as hex string, using 64-bit FNV-1a.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+/// The short hash of the current commit of the workspace, with -dirty appended if there are uncommitted
+/// changes. Returns unknown if it cannot be determined, like if git is not installed.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(env!("CARGO_MANIFEST_DIR"))
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{commit}-dirty"),
+        _ => commit,
+    }
+}
+
+fn part_name(part: Part) -> &'static str {
+    match part {
+        Part::A => "a",
+        Part::B => "b",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::history::{baseline, input_hash, slowdown, Record};
+    use aoc_solvers::{BenchStats, Part};
+    use std::time::Duration;
+
+    fn record(
+        commit: &str,
+        median_millis: u64,
+    ) -> Record {
+        let median = Duration::from_millis(median_millis);
+
+        Record {
+            year: 2024,
+            day: 20,
+            part: Part::A,
+            commit: commit.to_string(),
+            input_hash: input_hash("input"),
+            stats: BenchStats {
+                runs: 10,
+                min: median,
+                median,
+                mean: median,
+                std_dev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn records_can_be_read_back() {
+        let line = record("3f2c1d0", 12).to_line();
+        let parsed = Record::parse(&line).unwrap();
+
+        assert_eq!(parsed.to_line(), line);
+        assert_eq!(parsed.part, Part::A);
+        assert_eq!(parsed.stats.median, Duration::from_millis(12));
+        assert!(Record::parse("2024 20 a 3f2c1d0").is_err());
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn regressions_are_measured_against_another_commit() {
+        let records = [record("aaaaaaa", 10), record("bbbbbbb", 20), record("ccccccc", 30)];
+        let current = record("ccccccc", 25);
+
+        let baseline = baseline(&records, &current).unwrap();
+        assert_eq!(baseline.commit, "bbbbbbb");
+        assert_eq!(slowdown(&current, baseline), 25.0);
+
+        let other_input = Record {
+            input_hash: input_hash("other input"),
+            ..record("ddddddd", 10)
+        };
+        assert!(super::baseline(&records, &other_input).is_none());
+    }
+}
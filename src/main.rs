@@ -1,101 +1,609 @@
-use aoc_solvers::{ExampleResult, RealResult, RunConfig};
+mod cli;
+mod config;
+mod diff;
+mod history;
+mod scaffold;
+mod summary;
+
+use crate::cli::{Cli, Command, HistoryArgs, PartFlags, ReportArgs, Span};
+use crate::history::Record;
+use crate::summary::Summary;
+use aoc_solvers::{
+    AllocationStats, BenchStats, ExampleResult, ExampleRun, Measured, Part, PuzzleResult, RealResult, Report, RunConfig,
+};
+use clap::Parser;
 use colored::Colorize;
+use helpers::trace::TraceConfig;
+use std::fs::{read_to_string, File};
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::Mutex;
 
-/// Execute a puzzle for a given year and day.
+/// Execute the puzzles for the given years and days.
 ///
-/// This binary takes 3 inputs:
-/// 1. The year (required): The year of the puzzle
-/// 2. The day (required): The day of the puzzle
-/// 3. The selection (optional): A string which contains a space separated list of selection identifiers. If not set, every example and puzzle will be executed. Possible values:
-///     - ea -> Run example A
-///     - eb -> Run example B
-///     - a -> Run puzzle A
-///     - b -> Run puzzle B
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let year = args[1].parse::<usize>().unwrap();
-    let day = args[2].parse::<usize>().unwrap();
-
-    let selection_string = args.get(3);
-
-    let run_config = match selection_string {
-        Some(selection) => {
-            let parts = selection.split(" ").collect::<Vec<_>>();
-
-            RunConfig {
-                run_example_a: parts.contains(&"ea"),
-                run_a: parts.contains(&"a"),
-                run_example_b: parts.contains(&"eb"),
-                run_b: parts.contains(&"b"),
+/// Run with `help` to see every command and its arguments. Examples:
+/// - `run 2024 5` -> Run every example and puzzle of 2024 day 5
+/// - `run 2024 1..=10 --ea -a` -> Run example A and puzzle A of 2024 day 1 to 10
+/// - `all` -> Run every example and puzzle of every existing solver and print a summary
+/// - `all --json results.json --junit results.xml` -> Same as above, but also write the results as JSON and JUnit XML
+/// - `list 2024` -> List every day of 2024 a solver exists for
+/// - `bench 2023 5 -b --runs 20` -> Run puzzle B of 2023 day 5 20 times and print timing statistics
+/// - `bench 2023 5 --compare --threshold 5` -> Fail if a puzzle got more than 5% slower than on the last recorded commit
+/// - `trend 2023 5` -> Print the recorded benchmark results of 2023 day 5 for every commit
+/// - `new 2025 13` -> Create the solver module and input directory for 2025 day 13
+/// - `--input ../aoc-inputs all` -> Run every solver with the inputs from another directory
+/// - `run 2024 21 --trace 2024/21` -> Run 2024 day 21 and show all of its debug messages
+/// - `answer 2024 5 a 1234 too_high` -> Record that 1234 was too high for puzzle A of 2024 day 5
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let input = cli.input.as_deref();
+    // The notes of a day are details like the debug messages, so they are shown with them
+    let show_notes = cli.verbose > 0;
+
+    if let Err(e) = init_trace(&cli) {
+        return error(e);
+    }
+
+    match cli.command {
+        Command::Run {
+            years,
+            days,
+            parts,
+            reports,
+        } => match run_config(&parts, input) {
+            Ok(run_config) => run(&years, &days, &run_config, &reports, show_notes),
+            Err(e) => error(e),
+        },
+        Command::All { parts, reports } => match run_config(&parts, input) {
+            Ok(run_config) => all(&run_config, &reports, show_notes),
+            Err(e) => error(e),
+        },
+        Command::List { years } => list(years.as_ref()),
+        Command::Bench {
+            year,
+            day,
+            parts,
+            runs,
+            history,
+        } => match run_config(&parts, input) {
+            Ok(run_config) => bench(year, day, &run_config, runs, &history),
+            Err(e) => error(e),
+        },
+        Command::Trend { year, day, history } => match config::existing_input_root(input) {
+            Ok(input_root) => trend(&input_root, year, day, &config::bench_history(history.as_deref())),
+            Err(e) => error(e),
+        },
+        Command::New { year, day } => new(input, year, day),
+        Command::Answer {
+            year,
+            day,
+            part,
+            answer,
+            verdict,
+        } => match config::existing_input_root(input)
+            .and_then(|input_root| aoc_solvers::record_answer(&input_root, year, day, part, &answer, verdict))
+        {
+            Ok(()) => {
+                println!("Recorded {} as {verdict}", answer.trim());
+                ExitCode::SUCCESS
             }
-        }
-        // No part string is provided, so every example and puzzle will be executed
-        None => RunConfig {
-            run_example_a: true,
-            run_a: true,
-            run_example_b: true,
-            run_b: true,
+            Err(e) => error(e),
         },
-    };
+    }
+}
 
-    let res = match aoc_solvers::solve(day as u8, year as u16, run_config) {
-        Some(r) => r,
-        None => panic!("No solver exist for year {year} and day {day}!"),
+/// Enable the debug messages of the solvers, as set by the verbosity and trace flags.
+fn init_trace(cli: &Cli) -> Result<(), String> {
+    let file = match &cli.trace_file {
+        Some(path) => Some(File::create(path).map_err(|e| format!("Failed to create {}: {e}", path.display()))?),
+        None => None,
     };
 
-    if let Some(ea) = res.example_a_result {
-        match ea {
-            ExampleResult::DoesNotExist => println!("Example A does not exist yet, skipping it"),
-            ExampleResult::Works => println!("{}", "Example A works".green()),
-            ExampleResult::Fails { expected, was } => println!(
-                "{}",
-                format!("Example A failed. Expected was {expected}, but result was {was}.").red()
-            ),
+    helpers::trace::init(TraceConfig {
+        verbosity: cli.verbose,
+        scopes: cli.trace.clone(),
+        file: file.map(Mutex::new),
+    });
+
+    Ok(())
+}
+
+/// Create the run configuration for the given flags. Fails if the input root does not exist.
+fn run_config(
+    parts: &PartFlags,
+    input: Option<&Path>,
+) -> Result<RunConfig, String> {
+    Ok(parts.run_config(config::existing_input_root(input)?))
+}
+
+fn run(
+    years: &Span<u16>,
+    days: &Span<u8>,
+    run_config: &RunConfig,
+    reports: &ReportArgs,
+    show_notes: bool,
+) -> ExitCode {
+    // A single selected day must exist, but missing days in a range are just skipped
+    if years.is_single() && days.is_single() {
+        let (year, day) = (*years.range().start(), *days.range().start());
+
+        if !aoc_solvers::solver_exists(day, year) {
+            return error(format!("No solver exists for year {year} and day {day}"));
         }
     }
 
-    if let Some(eb) = res.example_b_result {
-        match eb {
-            ExampleResult::DoesNotExist => println!("Example B does not exist yet, skipping it"),
-            ExampleResult::Works => println!("{}", "Example B works".green()),
-            ExampleResult::Fails { expected, was } => println!(
-                "{}",
-                format!("Example B failed. Expected was {expected}, but result was {was}.").red()
-            ),
+    let mut solved_any = false;
+    let mut failed_any = false;
+    let mut report = Report::default();
+
+    for year in years.range() {
+        for day in days.range() {
+            if let Some(res) = aoc_solvers::solve(day, year, run_config.clone()) {
+                print_day_header(year, day, &res, show_notes);
+                print_result(&res);
+                solved_any = true;
+                failed_any |= res.has_failures();
+                report.add(year, day, &res);
+            }
         }
     }
 
-    if let Some(a) = res.a_result {
-        match a {
-            RealResult::DoesNotExist => {
-                println!("The puzzle input does not exist yet, skipping it")
+    if !solved_any {
+        return error("No solver exists for the given years and days");
+    }
+
+    if let Err(e) = write_reports(&report, reports) {
+        return error(e);
+    }
+
+    if failed_any {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Run every existing solver and print a summary matrix. Only the details of failing days are printed.
+fn all(
+    run_config: &RunConfig,
+    reports: &ReportArgs,
+    show_notes: bool,
+) -> ExitCode {
+    let mut summary = Summary::default();
+    let mut report = Report::default();
+
+    for (year, day) in aoc_solvers::implemented_days() {
+        if let Some(res) = aoc_solvers::solve(day, year, run_config.clone()) {
+            if res.has_failures() {
+                print_day_header(year, day, &res, show_notes);
+                print_result(&res);
             }
-            RealResult::Output(solution) => println!("Solution A: {solution}"),
-            RealResult::Works(val) => println!(
-                "{}",
-                format!("Puzzle A works, returning {val} as expected").green()
-            ),
-            RealResult::Fails { expected, was } => println!(
-                "{}",
-                format!("Puzzle A failed. Expected {expected}, but result was {was}").red()
-            ),
-        }
-    }
-
-    if let Some(b) = res.b_result {
-        match b {
-            RealResult::DoesNotExist => {
-                println!("The puzzle input does not exist yet, skipping it")
+
+            report.add(year, day, &res);
+            summary.add(year, day, res);
+        }
+    }
+
+    summary.print();
+
+    if let Err(e) = write_reports(&report, reports) {
+        return error(e);
+    }
+
+    if summary.has_failures() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Write the given report to every requested report file.
+fn write_reports(
+    report: &Report,
+    reports: &ReportArgs,
+) -> Result<(), String> {
+    let writes = [
+        (&reports.json, Report::to_json as fn(&Report) -> String),
+        (&reports.junit, Report::to_junit_xml),
+    ];
+
+    for (path, serialize) in writes {
+        if let Some(path) = path {
+            std::fs::write(path, serialize(report))
+                .map_err(|e| format!("Failed to write report to {}: {e}", path.display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the selected parts of a single day repeatedly and print statistics about their durations.
+/// The results of the puzzles are recorded in the benchmark history and optionally compared with earlier results.
+fn bench(
+    year: u16,
+    day: u8,
+    run_config: &RunConfig,
+    runs: usize,
+    history: &HistoryArgs,
+) -> ExitCode {
+    // A single run has no statistics, so nothing could be shown or recorded
+    if runs < 2 {
+        return error("The number of runs must be at least 2");
+    }
+
+    let bench_config = RunConfig {
+        repetitions: runs,
+        ..run_config.clone()
+    };
+
+    let Some(res) = aoc_solvers::solve(day, year, bench_config) else {
+        return error(format!("No solver exists for year {year} and day {day}"));
+    };
+
+    print_result(&res);
+    println!();
+
+    let example_stats = [("A", &res.example_a_results), ("B", &res.example_b_results)]
+        .into_iter()
+        .flat_map(|(part, examples)| examples.iter().map(move |example| (part, example)))
+        .map(|(part, example)| (example_label(part, example), example.measured.bench));
+    let puzzle_stats = [
+        ("Puzzle A".to_string(), res.a_result.as_ref().and_then(|r| r.bench)),
+        ("Puzzle B".to_string(), res.b_result.as_ref().and_then(|r| r.bench)),
+    ];
+
+    for (name, stats) in example_stats.chain(puzzle_stats) {
+        if let Some(stats) = stats {
+            print_bench_stats(&name, &stats);
+        }
+    }
+
+    let regressions = match record_bench(year, day, &run_config.input_root, &res, history) {
+        Ok(regressions) => regressions,
+        Err(e) => return error(e),
+    };
+
+    if res.has_failures() || regressions {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Record the benchmark results of the puzzles in the history. If they are compared, tells if any of them got
+/// slower than the threshold allows.
+fn record_bench(
+    year: u16,
+    day: u8,
+    input_root: &Path,
+    res: &PuzzleResult,
+    args: &HistoryArgs,
+) -> Result<bool, String> {
+    let Some(input_hash) = puzzle_input_hash(input_root, year, day) else {
+        return Ok(false);
+    };
+    let commit = history::current_commit();
+    let records = [(Part::A, &res.a_result), (Part::B, &res.b_result)]
+        .into_iter()
+        .filter_map(|(part, result)| {
+            Some(Record {
+                year,
+                day,
+                part,
+                commit: commit.clone(),
+                input_hash: input_hash.clone(),
+                stats: result.as_ref()?.bench?,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if records.is_empty() {
+        return Ok(false);
+    }
+
+    let path = config::bench_history(args.history.as_deref());
+    let mut regressions = false;
+
+    if args.compare {
+        let recorded = history::load(&path)?;
+        println!();
+
+        for record in &records {
+            let name = format!("Puzzle {:?}", record.part);
+
+            let Some(baseline) = history::baseline(&recorded, record) else {
+                println!("{name:<10} {}", "no earlier result is recorded".dimmed());
+                continue;
+            };
+
+            let slowdown = history::slowdown(record, baseline);
+            let comparison = format!(
+                "median {:.2?}, {:.2?} at {}: {slowdown:+.1}%",
+                record.stats.median, baseline.stats.median, baseline.commit
+            );
+
+            if slowdown > args.threshold {
+                regressions = true;
+                println!("{name:<10} {}", format!("{comparison}, slower than the threshold of {}%", args.threshold).red());
+            } else {
+                println!("{name:<10} {}", comparison.green());
             }
-            RealResult::Output(solution) => println!("Solution B: {solution}"),
-            RealResult::Works(val) => println!(
-                "{}",
-                format!("Puzzle B works, returning {val} as expected").green()
-            ),
-            RealResult::Fails { expected, was } => println!(
-                "{}",
-                format!("Puzzle B failed. Expected {expected}, but result was {was}").red()
-            ),
         }
     }
+
+    history::append(&path, &records)?;
+    println!("{}", format!("Recorded the results of commit {commit} in {}", path.display()).dimmed());
+
+    Ok(regressions)
+}
+
+/// Print how the recorded benchmark results of a day changed over the commits.
+fn trend(
+    input_root: &Path,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> ExitCode {
+    let Some(input_hash) = puzzle_input_hash(input_root, year, day) else {
+        return error(format!("The puzzle input of {year} day {day} does not exist"));
+    };
+
+    match history::load(path) {
+        Ok(records) => {
+            history::print_trend(&records, year, day, &input_hash);
+            ExitCode::SUCCESS
+        }
+        Err(e) => error(e),
+    }
+}
+
+/// The hash of the puzzle input of the given day, if it exists. The line endings are unified like the runner does.
+fn puzzle_input_hash(
+    input_root: &Path,
+    year: u16,
+    day: u8,
+) -> Option<String> {
+    let path = input_root.join(year.to_string()).join(day.to_string()).join("p");
+    let input = read_to_string(path).ok()?.replace("\r\n", "\n");
+
+    Some(history::input_hash(&input))
+}
+
+fn print_bench_stats(
+    name: &str,
+    stats: &BenchStats,
+) {
+    println!(
+        "{name:<10} runs: {}, min: {:.2?}, median: {:.2?}, mean: {:.2?}, std dev: {:.2?}",
+        stats.runs, stats.min, stats.median, stats.mean, stats.std_dev
+    );
+}
+
+/// List the implemented days of the given years, or of every year if none are given.
+fn list(years: Option<&Span<u16>>) -> ExitCode {
+    let days = aoc_solvers::implemented_days();
+
+    for year in aoc_solvers::implemented_years() {
+        if years.is_some_and(|years| !years.range().contains(&year)) {
+            continue;
+        }
+
+        let year_days = days
+            .iter()
+            .filter(|(y, _)| *y == year)
+            .map(|(_, day)| day.to_string())
+            .collect::<Vec<_>>();
+
+        println!("{year}: {}", year_days.join(", "));
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Create the solver module and input directory of a new day.
+fn new(
+    input: Option<&Path>,
+    year: u16,
+    day: u8,
+) -> ExitCode {
+    match config::input_root(input).and_then(|input_root| scaffold::create_day(&input_root, year, day)) {
+        Ok(created) => {
+            created.iter().for_each(|path| println!("Created {}", path.display()));
+            ExitCode::SUCCESS
+        }
+        Err(e) => error(e),
+    }
+}
+
+/// Print the year and day, followed by the title and tags of the day, if its metadata sets them.
+/// The notes of the day are printed below if they should be shown.
+fn print_day_header(
+    year: u16,
+    day: u8,
+    res: &PuzzleResult,
+    show_notes: bool,
+) {
+    let title = match &res.meta.title {
+        Some(title) => format!("{year} day {day}: {title}"),
+        None => format!("{year} day {day}"),
+    };
+
+    if res.meta.tags.is_empty() {
+        println!("{}", title.bold());
+    } else {
+        println!("{} {}", title.bold(), format!("[{}]", res.meta.tags.join(", ")).dimmed());
+    }
+
+    if let Some(notes) = res.meta.notes.as_ref().filter(|_| show_notes) {
+        for line in notes.lines() {
+            println!("{}", line.dimmed());
+        }
+    }
+}
+
+fn print_result(res: &PuzzleResult) {
+    for (part, examples) in [("A", &res.example_a_results), ("B", &res.example_b_results)] {
+        for example in examples {
+            print_example(part, example);
+        }
+    }
+
+    for (part, measured) in [(Part::A, &res.a_result), (Part::B, &res.b_result)] {
+        if let Some(measured) = measured {
+            print_puzzle(part, measured);
+        }
+    }
+}
+
+fn print_puzzle(
+    part: Part,
+    measured: &Measured<RealResult>,
+) {
+    match &measured.result {
+        RealResult::DoesNotExist => {
+            println!("The puzzle input does not exist yet, skipping it")
+        }
+        RealResult::Output(solution) => println!("Solution {part:?}: {solution}{}", timing(measured)),
+        RealResult::KnownWrong { was, reason } => println!(
+            "{}{}",
+            format!("Solution {part:?}: {was} is known to be wrong, {reason}").yellow(),
+            timing(measured)
+        ),
+        RealResult::Works(val) => println!(
+            "{}{}",
+            format!("Puzzle {part:?} works, returning {val} as expected").green(),
+            timing(measured)
+        ),
+        RealResult::Fails { expected, was } => {
+            println!(
+                "{}{}",
+                format!("Puzzle {part:?} failed. Expected {expected}, but result was {was}").red(),
+                timing(measured)
+            );
+            diff::print_mismatch(expected, was);
+        }
+        RealResult::Failed { chain } => println!(
+            "{}{}",
+            format!("Puzzle {part:?} returned an error: {}", chain.join(": ")).red(),
+            timing(measured)
+        ),
+        RealResult::Panicked { message } => {
+            println!("{}", format!("Puzzle {part:?} panicked: {message}").red())
+        }
+        RealResult::TimedOut { after } => {
+            println!("{}", format!("Puzzle {part:?} timed out after {after:.2?}").red())
+        }
+        RealResult::NotImplemented => println!("{}", format!("Puzzle {part:?} is not implemented yet").yellow()),
+    }
+}
+
+fn print_example(
+    part: &str,
+    example: &ExampleRun,
+) {
+    let label = example_label(part, example);
+    let measured = &example.measured;
+
+    match &measured.result {
+        ExampleResult::DoesNotExist => println!("{label} does not exist yet, skipping it"),
+        ExampleResult::Works(_) => println!("{}{}", format!("{label} works").green(), timing(measured)),
+        ExampleResult::Fails { expected, was } => {
+            println!(
+                "{}{}",
+                format!("{label} failed. Expected was {expected}, but result was {was}.").red(),
+                timing(measured)
+            );
+            diff::print_mismatch(expected, was);
+        }
+        ExampleResult::Failed { chain } => println!(
+            "{}{}",
+            format!("{label} returned an error: {}", chain.join(": ")).red(),
+            timing(measured)
+        ),
+        ExampleResult::Panicked { message } => {
+            println!("{}", format!("{label} panicked: {message}").red())
+        }
+        ExampleResult::TimedOut { after } => {
+            println!("{}", format!("{label} timed out after {after:.2?}").red())
+        }
+        ExampleResult::NotImplemented => println!("{}", format!("{label} is not implemented yet").yellow()),
+    }
+}
+
+/// The label of an example, like "Example A" or "Example A (ea2)". The name of the default example file
+/// (ea or eb) is omitted.
+fn example_label(
+    part: &str,
+    example: &ExampleRun,
+) -> String {
+    if example.name == format!("e{}", part.to_lowercase()) {
+        format!("Example {part}")
+    } else {
+        format!("Example {part} ({})", example.name)
+    }
+}
+
+/// Format the duration of the given measurement, to be appended to a result line. The duration of parsing
+/// is shown separately, if the solver parses its input separately, followed by the allocations if they are tracked.
+fn timing<R>(measured: &Measured<R>) -> String {
+    let Some(duration) = measured.duration else {
+        return String::new();
+    };
+
+    let allocations = measured
+        .allocations
+        .map(|stats| format!(", {}", format_allocations(&stats)))
+        .unwrap_or_default();
+    let parsing = measured
+        .parse_duration
+        .map(|parse_duration| {
+            let allocations = measured
+                .parse_allocations
+                .map(|stats| format!(" with {}", format_allocations(&stats)))
+                .unwrap_or_default();
+
+            format!(", parsed in {parse_duration:.2?}{allocations}")
+        })
+        .unwrap_or_default();
+
+    format!(" ({duration:.2?}{allocations}{parsing})").dimmed().to_string()
+}
+
+/// Format the given allocation statistics, like peak 1.50 MiB, 1200 allocations of 3.20 MiB.
+fn format_allocations(stats: &AllocationStats) -> String {
+    format!(
+        "peak {}, {} allocation{} of {}",
+        format_bytes(stats.peak_bytes),
+        stats.allocations,
+        if stats.allocations == 1 { "" } else { "s" },
+        format_bytes(stats.allocated_bytes)
+    )
+}
+
+/// Format the given amount of bytes with the largest fitting binary unit, like 1.50 MiB.
+fn format_bytes(bytes: usize) -> String {
+    let units = ["KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = "B";
+
+    for next_unit in units {
+        if size < 1024.0 {
+            break;
+        }
+
+        size /= 1024.0;
+        unit = next_unit;
+    }
+
+    if unit == "B" {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.2} {unit}")
+    }
+}
+
+/// Print the given error message and return a failing exit code.
+fn error(message: impl AsRef<str>) -> ExitCode {
+    eprintln!("{}", format!("error: {}", message.as_ref()).red());
+    ExitCode::FAILURE
 }
@@ -0,0 +1,94 @@
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// The files every input directory contains. Empty files are treated as missing, so they can be filled in later.
+const INPUT_FILES: [&str; 3] = ["ea", "eb", "p"];
+
+/// The metadata of a new day, where every entry is commented out until it is known.
+const META_TEMPLATE: &str = "# title = \"\"
+# tags = []
+
+[answers]
+# a = 0
+# b = 0
+";
+
+/// Create the solver module and the input directory for the given day.
+///
+/// The solver module is created at aoc_solvers/src/y<year>/d<day>.rs and contains the registered
+/// solver functions for both parts. The input directory is created at <input root>/<year>/<day>/ and contains
+/// empty files for the examples and the puzzle input and the metadata file meta.toml.
+///
+/// Nothing is created if any of these files already exists. Returns the paths of the created files.
+pub fn create_day(
+    input_root: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("{day} is not a valid day, it must be between 1 and 25"));
+    }
+
+    let module = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("aoc_solvers")
+        .join("src")
+        .join(format!("y{year}"))
+        .join(format!("d{day}.rs"));
+    let input_dir = input_root.join(year.to_string()).join(day.to_string());
+    let input_files = INPUT_FILES.map(|file| input_dir.join(file));
+    let meta = input_dir.join("meta.toml");
+
+    if let Some(existing) = input_files.iter().chain([&meta, &module]).find(|path| path.exists()) {
+        return Err(format!("{} already exists, refusing to overwrite it", existing.display()));
+    }
+
+    let module_dir = module.parent().expect("the module should have a parent directory");
+
+    create_dir(module_dir)?;
+    write_file(&module, &module_template(year, day))?;
+    create_dir(&input_dir)?;
+
+    for file in &input_files {
+        write_file(file, "")?;
+    }
+
+    write_file(&meta, META_TEMPLATE)?;
+
+    Ok([module].into_iter().chain(input_files).chain([meta]).collect())
+}
+
+fn module_template(
+    year: u16,
+    day: u8,
+) -> String {
+    format!(
+        "use crate::NotImplemented;
+use proc_macros::solver;
+
+#[solver(year = {year}, day = {day}, part = a)]
+pub fn solve_a(_input: &str) -> NotImplemented {{
+    NotImplemented
+}}
+
+#[solver(year = {year}, day = {day}, part = b)]
+pub fn solve_b(_input: &str) -> NotImplemented {{
+    NotImplemented
+}}
+"
+    )
+}
+
+fn create_dir(dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))
+}
+
+fn write_file(
+    path: &Path,
+    content: &str,
+) -> Result<(), String> {
+    File::create_new(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
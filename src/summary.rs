@@ -0,0 +1,187 @@
+use aoc_solvers::{ExampleResult, ExampleRun, PuzzleResult, RealResult};
+use colored::{ColoredString, Colorize};
+use std::collections::BTreeMap;
+
+/// Collects the results of many puzzles and prints them as a compact year × day matrix.
+///
+/// Every day is printed as a cell with two symbols, one for part A and one for part B.
+/// The examples and the real puzzles get their own row per year. If a part has multiple examples,
+/// its cell shows the worst result and every example is listed below the matrix.
+#[derive(Default)]
+pub struct Summary {
+    results: BTreeMap<(u16, u8), PuzzleResult>,
+}
+
+impl Summary {
+    pub fn add(
+        &mut self,
+        year: u16,
+        day: u8,
+        result: PuzzleResult,
+    ) {
+        self.results.insert((year, day), result);
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.results.values().any(PuzzleResult::has_failures)
+    }
+
+    pub fn print(&self) {
+        let years = self
+            .results
+            .keys()
+            .map(|(year, _)| *year)
+            .collect::<Vec<_>>();
+        let (Some(first_year), Some(last_year)) = (years.first(), years.last()) else {
+            println!("No puzzles were executed");
+            return;
+        };
+
+        let header = aoc_solvers::DAYS
+            .map(|day| format!("{day:<3}"))
+            .collect::<String>();
+        println!("{:10}{header}", "");
+
+        for year in *first_year..=*last_year {
+            let example_row = self.row(year, |res| {
+                [&res.example_a_results, &res.example_b_results].map(|examples| {
+                    examples
+                        .iter()
+                        .map(|example| &example.measured.result)
+                        .max_by_key(|result| severity(result))
+                        .map_or_else(|| "·".dimmed(), example_symbol)
+                })
+            });
+            let real_row = self.row(year, |res| {
+                [&res.a_result, &res.b_result].map(|real| match real.as_ref().map(|real| &real.result) {
+                    Some(RealResult::Works(_)) => "✓".green(),
+                    Some(RealResult::Fails { .. }) => "✗".red(),
+                    Some(RealResult::Failed { .. }) => "E".red(),
+                    Some(RealResult::Panicked { .. }) => "!".red(),
+                    Some(RealResult::TimedOut { .. }) => "⧖".red(),
+                    Some(RealResult::Output(_)) => "?".yellow(),
+                    Some(RealResult::NotImplemented) => "-".yellow(),
+                    Some(RealResult::KnownWrong { .. }) => "✗".yellow(),
+                    Some(RealResult::DoesNotExist) | None => "·".dimmed(),
+                })
+            });
+
+            println!("{year} {:5}{example_row}", "ex");
+            println!("{:5}{:5}{real_row}", "", "real");
+        }
+
+        self.print_multiple_examples();
+
+        println!();
+        self.print_stars();
+        println!(
+            "{} works, {} fails, {} known to be wrong, {} returned an error, {} panicked, {} timed out, {} without expected result, {} not implemented, {} missing",
+            "✓".green(),
+            "✗".red(),
+            "✗".yellow(),
+            "E".red(),
+            "!".red(),
+            "⧖".red(),
+            "?".yellow(),
+            "-".yellow(),
+            "·".dimmed()
+        );
+    }
+
+    /// Print how many stars the executed puzzles earned, which is one for every part returning its solution,
+    /// and how many parts are not implemented yet. Parts without an input are not counted.
+    fn print_stars(&self) {
+        let results = self
+            .results
+            .values()
+            .flat_map(|res| [&res.a_result, &res.b_result])
+            .flatten()
+            .map(|real| &real.result)
+            .filter(|result| !matches!(result, RealResult::DoesNotExist))
+            .collect::<Vec<_>>();
+        let stars = results
+            .iter()
+            .filter(|result| matches!(result, RealResult::Works(_)))
+            .count();
+        let not_implemented = results
+            .iter()
+            .filter(|result| matches!(result, RealResult::NotImplemented))
+            .count();
+
+        println!(
+            "{} of {} stars, {not_implemented} not implemented",
+            stars.to_string().yellow(),
+            results.len()
+        );
+    }
+
+    /// Print the result of every example of the days which have multiple examples for a part.
+    fn print_multiple_examples(&self) {
+        let days = self
+            .results
+            .iter()
+            .filter(|(_, res)| res.example_a_results.len() > 1 || res.example_b_results.len() > 1)
+            .collect::<Vec<_>>();
+
+        if !days.is_empty() {
+            println!();
+        }
+
+        for ((year, day), res) in days {
+            let examples = |examples: &[ExampleRun]| {
+                examples
+                    .iter()
+                    .map(|example| format!("{} {}", example_symbol(&example.measured.result), example.name))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            };
+
+            println!(
+                "{year} day {day:<2}  A: {}  B: {}",
+                examples(&res.example_a_results),
+                examples(&res.example_b_results)
+            );
+        }
+    }
+
+    fn row(
+        &self,
+        year: u16,
+        symbols: impl Fn(&PuzzleResult) -> [ColoredString; 2],
+    ) -> String {
+        aoc_solvers::DAYS
+            .map(|day| match self.results.get(&(year, day)) {
+                Some(res) => {
+                    let [a, b] = symbols(res);
+                    format!("{a}{b} ")
+                }
+                None => "   ".to_string(),
+            })
+            .collect()
+    }
+}
+
+fn example_symbol(result: &ExampleResult) -> ColoredString {
+    match result {
+        ExampleResult::Works(_) => "✓".green(),
+        ExampleResult::Fails { .. } => "✗".red(),
+        ExampleResult::Failed { .. } => "E".red(),
+        ExampleResult::Panicked { .. } => "!".red(),
+        ExampleResult::TimedOut { .. } => "⧖".red(),
+        ExampleResult::NotImplemented => "-".yellow(),
+        ExampleResult::DoesNotExist => "·".dimmed(),
+    }
+}
+
+/// How bad the given result is, to show the worst result of multiple examples.
+fn severity(result: &ExampleResult) -> u8 {
+    match result {
+        ExampleResult::DoesNotExist => 0,
+        ExampleResult::Works(_) => 1,
+        ExampleResult::NotImplemented => 2,
+        ExampleResult::Fails { .. } => 3,
+        ExampleResult::Failed { .. } => 4,
+        ExampleResult::TimedOut { .. } => 5,
+        ExampleResult::Panicked { .. } => 6,
+    }
+}